@@ -0,0 +1,53 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use nesti::{Integer, Nesti};
+
+fn populated(nodes: usize) -> Nesti {
+    let nesti = Nesti::new();
+    for i in 0..nodes {
+        nesti.put(format!("workers/{}/jobs/{}", i % 100, i), Integer(i));
+    }
+    nesti
+}
+
+fn put_existing(c: &mut Criterion) {
+    let mut group = c.benchmark_group("put_existing");
+    for nodes in [100, 1_000, 10_000] {
+        let nesti = populated(nodes);
+        let path = format!("workers/{}/jobs/{}", (nodes / 2) % 100, nodes / 2);
+        group.bench_with_input(BenchmarkId::from_parameter(nodes), &nodes, |b, _| {
+            b.iter(|| nesti.put(black_box(path.as_str()), Integer(1u64)))
+        });
+    }
+    group.finish();
+}
+
+fn put_new(c: &mut Criterion) {
+    let mut group = c.benchmark_group("put_new");
+    for nodes in [100, 1_000, 10_000] {
+        group.bench_with_input(BenchmarkId::from_parameter(nodes), &nodes, |b, &nodes| {
+            b.iter_batched(
+                || populated(nodes),
+                |nesti| nesti.put(black_box("workers/new/jobs/0"), Integer(1u64)),
+                criterion::BatchSize::LargeInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+fn pop(c: &mut Criterion) {
+    let mut group = c.benchmark_group("pop");
+    for nodes in [100, 1_000, 10_000] {
+        group.bench_with_input(BenchmarkId::from_parameter(nodes), &nodes, |b, &nodes| {
+            b.iter_batched(
+                || populated(nodes),
+                |nesti| nesti.pop(black_box("workers/1")),
+                criterion::BatchSize::LargeInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, put_existing, put_new, pop);
+criterion_main!(benches);
@@ -2,8 +2,8 @@
 mod system_info {
     use humanize_duration::Truncate::Millis;
     use nesti::{
-        nesti, nesti_task, Bytes, Cyan, Integer, IntegerUnit, Magenta, Nano, Progress, Timer,
-        Yellow,
+        nesti, nesti_pop_matching, nesti_task, Bytes, Cyan, Integer, IntegerUnit, Magenta, Nano,
+        Progress, Timer, Yellow,
     };
     use rand::{rng, Rng};
     use std::{
@@ -30,6 +30,12 @@ mod system_info {
                 sleep(Duration::from_millis(1000));
             }
 
+            // Clear out the randomly keyed timers without tracking their keys
+            if timers == 5 && rng.random_bool(0.05) {
+                nesti_pop_matching("system/timers/*");
+                timers = 0;
+            }
+
             nesti("system/uptime", Timer(Nano));
 
             nesti("system/online", rng.random_bool(0.5));
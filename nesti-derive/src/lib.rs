@@ -0,0 +1,205 @@
+//! `#[derive(NestiElement)]`, re-exported by `nesti` with the `derive`
+//! feature.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_macro_input, spanned::Spanned, Data, DeriveInput, Error, Field, Fields, Ident, LitStr,
+    Type,
+};
+
+/// Element kinds selected with a bare `#[nesti(..)]` word, and the element
+/// type the field is converted into.
+const KINDS: [(&str, &str); 8] = [
+    ("integer", "Integer"),
+    ("decimal", "Decimal"),
+    ("bytes", "Bytes"),
+    ("kilobytes", "Kilobytes"),
+    ("megabytes", "Megabytes"),
+    ("gigabytes", "Gigabytes"),
+    ("terabytes", "Terabytes"),
+    ("petabytes", "Petabytes"),
+];
+
+const INTEGERS: [&str; 12] = [
+    "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
+];
+
+const FLOATS: [&str; 2] = ["f32", "f64"];
+
+/// Render a struct as a subtree with one child per field, named after the
+/// field. Integer and float fields are shown as `Integer` and `Decimal`, any
+/// other field type must implement `Element` itself.
+///
+/// Fields take these attributes:
+///
+/// - `#[nesti(rename = "name")]` to use another segment name
+/// - `#[nesti(skip)]` to leave the field out
+/// - `#[nesti(color = Cyan)]` to wrap the field in a color element
+/// - `#[nesti(bytes)]` and the other size units, `integer` or `decimal` to
+///   pick the element the field is shown as. Size units take integer fields,
+///   values that do not fit a `u64`, such as negative ones, are shown as an
+///   error
+/// - `#[nesti(display)]` to show the field's `Display` output as text
+#[proc_macro_derive(NestiElement, attributes(nesti))]
+pub fn derive_nesti_element(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+#[derive(Default)]
+struct FieldOptions {
+    rename: Option<String>,
+    skip: bool,
+    color: Option<Ident>,
+    kind: Option<Kind>,
+}
+
+enum Kind {
+    Element(Ident),
+    Display,
+}
+
+fn expand(input: DeriveInput) -> Result<TokenStream2, Error> {
+    let Data::Struct(data) = &input.data else {
+        return Err(Error::new(
+            input.ident.span(),
+            "NestiElement can only be derived for structs",
+        ));
+    };
+
+    let fields = match &data.fields {
+        Fields::Named(fields) => fields.named.iter().collect(),
+        Fields::Unnamed(fields) => fields.unnamed.iter().collect(),
+        Fields::Unit => Vec::new(),
+    };
+
+    let mut children = Vec::new();
+
+    for (index, field) in fields.into_iter().enumerate() {
+        let options = field_options(field)?;
+        if options.skip {
+            continue;
+        }
+
+        let member = match &field.ident {
+            Some(ident) => quote!(#ident),
+            None => {
+                let index = syn::Index::from(index);
+                quote!(#index)
+            }
+        };
+        let segment = options
+            .rename
+            .clone()
+            .unwrap_or_else(|| match &field.ident {
+                Some(ident) => ident.to_string().trim_start_matches("r#").to_owned(),
+                None => index.to_string(),
+            });
+
+        // Sizes take a u64, values out of its range are shown as an error
+        // rather than wrapping around
+        if let Some(Kind::Element(element)) = options.kind.as_ref().filter(|k| k.is_size()) {
+            let size = colored(&options, quote!(::nesti::#element(size)));
+            children.push(quote! {
+                match ::std::convert::TryFrom::try_from(self.#member) {
+                    Ok(size) => children.put(#segment, &#size),
+                    Err(_) => children.put(
+                        #segment,
+                        &::nesti::Themed(
+                            ::nesti::Role::Error,
+                            ::nesti::Text(format!("{} out of range", self.#member)),
+                        ),
+                    ),
+                }
+            });
+            continue;
+        }
+
+        let value = match &options.kind {
+            Some(Kind::Element(element)) => Some(quote!(::nesti::#element(self.#member))),
+            Some(Kind::Display) => Some(quote!(::std::string::ToString::to_string(&self.#member))),
+            None if is_primitive(&field.ty, &INTEGERS) => {
+                Some(quote!(::nesti::Integer(self.#member)))
+            }
+            None if is_primitive(&field.ty, &FLOATS) => {
+                Some(quote!(::nesti::Decimal(self.#member)))
+            }
+            None => None,
+        };
+
+        children.push(match (value, &options.color) {
+            (Some(value), _) => {
+                let value = colored(&options, value);
+                quote!(children.put(#segment, &#value);)
+            }
+            (None, Some(color)) => quote!(children.put(#segment, &::nesti::#color(&self.#member));),
+            (None, None) => quote!(children.put(#segment, &self.#member);),
+        });
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        #[allow(clippy::unnecessary_fallible_conversions)]
+        impl #impl_generics ::nesti::Element for #name #ty_generics #where_clause {
+            fn children(&self, children: &mut ::nesti::Children) {
+                #(#children)*
+            }
+        }
+    })
+}
+
+fn field_options(field: &Field) -> Result<FieldOptions, Error> {
+    let mut options = FieldOptions::default();
+
+    for attr in field.attrs.iter().filter(|a| a.path().is_ident("nesti")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("rename") {
+                options.rename = Some(meta.value()?.parse::<LitStr>()?.value());
+            } else if meta.path.is_ident("skip") {
+                options.skip = true;
+            } else if meta.path.is_ident("color") {
+                options.color = Some(meta.value()?.parse()?);
+            } else if meta.path.is_ident("display") {
+                options.kind = Some(Kind::Display);
+            } else if let Some((_, element)) =
+                KINDS.iter().find(|(kind, _)| meta.path.is_ident(kind))
+            {
+                options.kind = Some(Kind::Element(Ident::new(element, meta.path.span())));
+            } else {
+                return Err(meta.error("unknown nesti attribute"));
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok(options)
+}
+
+fn is_primitive(ty: &Type, names: &[&str]) -> bool {
+    match ty {
+        Type::Path(path) if path.qself.is_none() => path
+            .path
+            .get_ident()
+            .is_some_and(|ident| names.iter().any(|name| ident == name)),
+        _ => false,
+    }
+}
+
+impl Kind {
+    fn is_size(&self) -> bool {
+        matches!(self, Kind::Element(element) if element != "Integer" && element != "Decimal")
+    }
+}
+
+/// `value` wrapped in the field's color element, if it has one.
+fn colored(options: &FieldOptions, value: TokenStream2) -> TokenStream2 {
+    match &options.color {
+        Some(color) => quote!(::nesti::#color(#value)),
+        None => value,
+    }
+}
@@ -0,0 +1,161 @@
+use nesti::{style::TextFg, testing::assert_tree, Nesti, NestiElement, Role};
+
+#[derive(NestiElement)]
+struct Service {
+    name: String,
+    #[nesti(rename = "up")]
+    healthy: bool,
+    requests: u64,
+    load: f64,
+    #[nesti(bytes)]
+    memory: u32,
+    #[nesti(skip)]
+    #[allow(dead_code)]
+    secret: String,
+}
+
+#[derive(NestiElement)]
+struct Cluster {
+    #[nesti(color = Cyan)]
+    region: &'static str,
+    #[nesti(display)]
+    version: Version,
+    primary: Service,
+}
+
+struct Version(u8, u8);
+
+impl std::fmt::Display for Version {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "v{}.{}", self.0, self.1)
+    }
+}
+
+#[derive(NestiElement)]
+struct Pair(u8, #[nesti(rename = "second")] &'static str);
+
+fn service() -> Service {
+    Service {
+        name: String::from("api"),
+        healthy: true,
+        requests: 12000,
+        load: 0.75,
+        memory: 2048,
+        secret: String::from("hunter2"),
+    }
+}
+
+#[test]
+fn fields_become_children() {
+    let nesti = Nesti::new();
+    nesti.put("svc", service());
+
+    assert_tree(
+        &nesti,
+        "
+        svc
+        |- name                         api
+        |- up                        TRUE ●
+        |- requests                  12,000
+        |- load                        0.75
+        `- memory                   2.0 KiB
+        ",
+    );
+}
+
+#[test]
+fn nested_structs_color_and_display() {
+    let nesti = Nesti::new();
+    nesti.put(
+        "cluster",
+        Cluster {
+            region: "eu-west",
+            version: Version(1, 4),
+            primary: service(),
+        },
+    );
+
+    assert_eq!(
+        nesti.get("cluster/version").unwrap().content.as_deref(),
+        Some("v1.4")
+    );
+    assert_eq!(
+        nesti
+            .get("cluster/primary/requests")
+            .unwrap()
+            .content
+            .as_deref(),
+        Some("12,000")
+    );
+    let region = nesti.get("cluster/region").unwrap();
+    assert!(region.style.unwrap().get::<TextFg>().is_some());
+}
+
+#[test]
+fn tuple_struct_fields_are_numbered() {
+    let nesti = Nesti::new();
+    nesti.put("pair", Pair(1, "b"));
+
+    assert_eq!(
+        nesti.children("pair").collect::<Vec<_>>(),
+        ["pair/0", "pair/second"]
+    );
+}
+
+#[test]
+fn updated_struct_updates_children() {
+    let nesti = Nesti::new();
+    nesti.put("svc", service());
+    nesti.put(
+        "svc",
+        Service {
+            requests: 13000,
+            ..service()
+        },
+    );
+
+    assert_eq!(
+        nesti.get("svc/requests").unwrap().content.as_deref(),
+        Some("13,000")
+    );
+}
+
+#[derive(NestiElement)]
+struct Transfer {
+    #[nesti(bytes)]
+    delta: i64,
+    #[nesti(bytes)]
+    buffered: usize,
+}
+
+#[test]
+fn signed_size_fields_are_range_checked() {
+    let nesti = Nesti::new();
+    nesti.put(
+        "transfer",
+        Transfer {
+            delta: -1,
+            buffered: 512,
+        },
+    );
+
+    let delta = nesti.get("transfer/delta").unwrap();
+    assert_eq!(delta.content.as_deref(), Some("-1 out of range"));
+    assert_eq!(delta.role, Some(Role::Error));
+    assert_eq!(
+        nesti.get("transfer/buffered").unwrap().content.as_deref(),
+        Some("512 B")
+    );
+
+    nesti.put(
+        "transfer",
+        Transfer {
+            delta: 2048,
+            buffered: 0,
+        },
+    );
+    assert_eq!(
+        nesti.get("transfer/delta").unwrap().content.as_deref(),
+        Some("2.0 KiB")
+    );
+}
@@ -0,0 +1,239 @@
+use std::env;
+
+use stanza::style::{
+    Bold, Italic, Strikethrough, Style, Styles as StanzaStyles, TextBg, TextFg, Underline,
+};
+
+use crate::style::{BackgroundColor, Color, TextColor};
+
+/// Colours the output target can show. Colours beyond it are downgraded to
+/// the nearest one it has.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ColorSupport {
+    /// No colours or text attributes at all.
+    None,
+    /// The 16 palette colours.
+    #[default]
+    Ansi16,
+    /// The xterm 256-colour palette.
+    Ansi256,
+    /// 24-bit RGB.
+    TrueColor,
+}
+
+impl ColorSupport {
+    /// Read the support from `NO_COLOR`, `COLORTERM` and `TERM`.
+    pub fn detect() -> Self {
+        Self::from_env(
+            env::var_os("NO_COLOR").is_some_and(|v| !v.is_empty()),
+            env::var("COLORTERM").ok().as_deref(),
+            env::var("TERM").ok().as_deref(),
+        )
+    }
+
+    fn from_env(no_color: bool, colorterm: Option<&str>, term: Option<&str>) -> Self {
+        if no_color || term == Some("dumb") {
+            return Self::None;
+        }
+        if matches!(colorterm, Some("truecolor" | "24bit")) {
+            return Self::TrueColor;
+        }
+        match term {
+            Some(term) if term.contains("256color") => Self::Ansi256,
+            _ => Self::Ansi16,
+        }
+    }
+}
+
+/// SGR parameters for `styles`, e.g. `38;5;208;1`, downgraded to `support`.
+/// Empty when there is nothing to switch on.
+pub(crate) fn sgr(styles: &StanzaStyles, support: ColorSupport) -> String {
+    if support == ColorSupport::None {
+        return String::new();
+    }
+
+    let mut params = Vec::new();
+    if let Some(TextColor(color)) = TextColor::resolve(styles) {
+        params.push(color_params(*color, 30, support));
+    } else if let Some(TextFg(color)) = TextFg::resolve(styles) {
+        params.extend(Color::try_from(color.clone()).map(|color| color_params(color, 30, support)));
+    }
+    if let Some(BackgroundColor(color)) = BackgroundColor::resolve(styles) {
+        params.push(color_params(*color, 40, support));
+    } else if let Some(TextBg(color)) = TextBg::resolve(styles) {
+        params.extend(Color::try_from(color.clone()).map(|color| color_params(color, 40, support)));
+    }
+
+    for (on, code) in [
+        (Bold::resolve(styles).is_some_and(|b| b.0), "1"),
+        (Italic::resolve(styles).is_some_and(|i| i.0), "3"),
+        (Underline::resolve(styles).is_some_and(|u| u.0), "4"),
+        (Strikethrough::resolve(styles).is_some_and(|s| s.0), "9"),
+    ] {
+        if on {
+            params.push(code.to_owned());
+        }
+    }
+
+    params.join(";")
+}
+
+/// Parameters selecting `color` as foreground (`base` 30) or background
+/// (`base` 40).
+fn color_params(color: Color, base: u8, support: ColorSupport) -> String {
+    match (color, support) {
+        (Color::Rgb(r, g, b), ColorSupport::TrueColor) => format!("{};2;{r};{g};{b}", base + 8),
+        (Color::Indexed(n), ColorSupport::TrueColor | ColorSupport::Ansi256) if n >= 16 => {
+            format!("{};5;{n}", base + 8)
+        }
+        (Color::Rgb(r, g, b), ColorSupport::Ansi256) => {
+            format!("{};5;{}", base + 8, rgb_to_indexed(r, g, b))
+        }
+        (color, _) => {
+            let n = match color {
+                Color::Indexed(n) if n < 16 => n,
+                Color::Indexed(n) => nearest_palette(indexed_to_rgb(n)),
+                Color::Rgb(r, g, b) => nearest_palette((r, g, b)),
+            };
+            match n < 8 {
+                true => (base + n).to_string(),
+                false => (base + 60 + n - 8).to_string(),
+            }
+        }
+    }
+}
+
+/// xterm's defaults for the 16 palette colours.
+const PALETTE: [(u8, u8, u8); 16] = [
+    (0, 0, 0),
+    (205, 0, 0),
+    (0, 205, 0),
+    (205, 205, 0),
+    (0, 0, 238),
+    (205, 0, 205),
+    (0, 205, 205),
+    (229, 229, 229),
+    (127, 127, 127),
+    (255, 0, 0),
+    (0, 255, 0),
+    (255, 255, 0),
+    (92, 92, 255),
+    (255, 0, 255),
+    (0, 255, 255),
+    (255, 255, 255),
+];
+
+/// Levels of the 6x6x6 colour cube starting at index 16.
+const CUBE: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+fn indexed_to_rgb(n: u8) -> (u8, u8, u8) {
+    match n {
+        0..=15 => PALETTE[n as usize],
+        16..=231 => {
+            let n = n - 16;
+            (
+                CUBE[(n / 36) as usize],
+                CUBE[(n / 6 % 6) as usize],
+                CUBE[(n % 6) as usize],
+            )
+        }
+        _ => {
+            let level = 8 + (n - 232) * 10;
+            (level, level, level)
+        }
+    }
+}
+
+/// Closest colour of the cube or the grey ramp, whichever is nearer.
+fn rgb_to_indexed(r: u8, g: u8, b: u8) -> u8 {
+    let level = |v: u8| match v {
+        0..=47 => 0,
+        48..=114 => 1,
+        _ => (v - 35) / 40,
+    };
+    let cube = 16 + 36 * level(r) + 6 * level(g) + level(b);
+
+    let average = (r as u16 + g as u16 + b as u16) / 3;
+    let grey = match average {
+        0..=7 => 16,
+        239.. => 231,
+        _ => 232 + ((average - 8) / 10).min(23) as u8,
+    };
+
+    match distance((r, g, b), indexed_to_rgb(grey)) < distance((r, g, b), indexed_to_rgb(cube)) {
+        true => grey,
+        false => cube,
+    }
+}
+
+fn nearest_palette(rgb: (u8, u8, u8)) -> u8 {
+    (0..16u8)
+        .min_by_key(|&n| distance(rgb, PALETTE[n as usize]))
+        .unwrap_or(7)
+}
+
+fn distance(a: (u8, u8, u8), b: (u8, u8, u8)) -> u32 {
+    let d = |x: u8, y: u8| (x as i32 - y as i32).pow(2) as u32;
+    d(a.0, b.0) + d(a.1, b.1) + d(a.2, b.2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ColorSupport;
+
+    #[test]
+    fn no_color_and_dumb_terminals_get_none() {
+        assert_eq!(
+            ColorSupport::from_env(true, Some("truecolor"), Some("xterm-256color")),
+            ColorSupport::None
+        );
+        assert_eq!(
+            ColorSupport::from_env(false, Some("truecolor"), Some("dumb")),
+            ColorSupport::None
+        );
+    }
+
+    #[test]
+    fn colorterm_selects_truecolor() {
+        for colorterm in ["truecolor", "24bit"] {
+            assert_eq!(
+                ColorSupport::from_env(false, Some(colorterm), Some("xterm")),
+                ColorSupport::TrueColor
+            );
+        }
+        assert_eq!(
+            ColorSupport::from_env(false, Some("24bit"), None),
+            ColorSupport::TrueColor
+        );
+    }
+
+    #[test]
+    fn term_256color_selects_ansi256() {
+        for term in ["xterm-256color", "screen-256color", "tmux-256color"] {
+            assert_eq!(
+                ColorSupport::from_env(false, None, Some(term)),
+                ColorSupport::Ansi256
+            );
+        }
+        assert_eq!(
+            ColorSupport::from_env(false, Some("yes"), Some("xterm-256color")),
+            ColorSupport::Ansi256
+        );
+    }
+
+    #[test]
+    fn anything_else_falls_back_to_ansi16() {
+        assert_eq!(
+            ColorSupport::from_env(false, None, None),
+            ColorSupport::Ansi16
+        );
+        assert_eq!(
+            ColorSupport::from_env(false, None, Some("xterm")),
+            ColorSupport::Ansi16
+        );
+        assert_eq!(
+            ColorSupport::from_env(false, Some("yes"), Some("linux")),
+            ColorSupport::Ansi16
+        );
+    }
+}
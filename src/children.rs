@@ -0,0 +1,217 @@
+use std::{any::type_name, collections::HashSet};
+
+use bevy_ecs::prelude::*;
+
+use crate::{
+    core::{ErrorMessage, Kind, Path},
+    elements::{Cells, NumberFormat, Unit},
+    index::PathIndex,
+    style::Styles,
+    Element, Role,
+};
+
+/// Rows an element puts below its own path, see [`Element::children`].
+///
+/// Children that were put by the element before but are not put again are
+/// removed along with everything below them.
+pub struct Children<'a, 'w> {
+    entity: &'a mut EntityWorldMut<'w>,
+    path: String,
+    style_override: Option<Styles>,
+    refresh: bool,
+    order: ChildOrder,
+    segments: Vec<String>,
+}
+
+/// How children are ordered among their siblings.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ChildOrder {
+    /// In the order each segment was first put.
+    #[default]
+    FirstSeen,
+    /// In the order of the latest put, e.g. for sorted or indexed items.
+    Emitted,
+}
+
+impl<'w> Children<'_, 'w> {
+    /// Put `element` at `<path>/<segment>`. A segment may contain further
+    /// `/` separators to reach deeper rows, and empty segments are ignored.
+    pub fn put<T: Element + ?Sized>(&mut self, segment: impl Into<String>, element: &T) {
+        let segment = segment.into();
+        if segment.is_empty() {
+            return;
+        }
+
+        let path = format!("{}/{segment}", self.path);
+        let style_override = self.style_override.clone();
+        let refresh = self.refresh;
+        self.entity.world_scope(|world| {
+            if refresh {
+                refresh_element(world, &path, element, style_override);
+            } else {
+                put_element(world, &path, element, style_override).insert(Kind(type_name::<T>()));
+            }
+        });
+
+        if !self.segments.contains(&segment) {
+            self.segments.push(segment);
+        }
+    }
+
+    pub fn set_order(&mut self, order: ChildOrder) {
+        self.order = order;
+    }
+
+    /// Style handed to the children put after this call, as wrappers such
+    /// as [`Cyan`](crate::Cyan) do.
+    pub fn set_style_override(&mut self, style_override: Option<Styles>) {
+        self.style_override = style_override;
+    }
+
+    /// Style handed to the children put from here on.
+    pub fn style_override(&self) -> Option<Styles> {
+        self.style_override.clone()
+    }
+
+    /// Entity of the element the children are put below.
+    #[cfg(feature = "serde")]
+    pub(crate) fn entity(&mut self) -> &mut EntityWorldMut<'w> {
+        self.entity
+    }
+
+    /// Path of the element the children are put below.
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+}
+
+/// Segments of the children an element put below its own path.
+#[derive(Component)]
+#[component(storage = "SparseSet")]
+struct EmittedChildren {
+    segments: Vec<String>,
+}
+
+pub(crate) fn despawn(world: &mut World, remove: impl FnOnce(&mut PathIndex) -> Vec<Entity>) {
+    let entities = remove(&mut world.resource_mut::<PathIndex>());
+
+    for entity in entities {
+        world.despawn(entity);
+    }
+}
+
+/// Spawn or tick `element` at `path` the way [`Nesti::put`](crate::Nesti::put)
+/// does, without storing it.
+pub(crate) fn put_element<'w, E: Element + ?Sized>(
+    world: &'w mut World,
+    path: &str,
+    element: &E,
+    style_override: Option<Styles>,
+) -> EntityWorldMut<'w> {
+    let Some(entity) = world.resource::<PathIndex>().get(path) else {
+        let entity = world.spawn(Path(path.to_owned())).id();
+        world.resource_mut::<PathIndex>().insert(path, entity);
+
+        let mut ent = world.entity_mut(entity);
+        element.spawn(&mut ent, style_override.clone());
+        emit_children(&mut ent, element, style_override, false);
+        return ent;
+    };
+
+    // Entity already exists at path
+    let mut ent = world.entity_mut(entity);
+    ent.remove::<ErrorMessage>();
+    if element.is_mutation() {
+        element.tick(&mut ent, style_override);
+        return ent;
+    }
+
+    ent.remove::<(NumberFormat, Unit, Role)>();
+    if let Some(mut cells) = ent.get_mut::<Cells>() {
+        cells.current = false;
+    }
+
+    element.tick(&mut ent, style_override.clone());
+
+    // Columns left over from a tuple the element replaced
+    if ent.get::<Cells>().is_some_and(|cells| !cells.current) {
+        let columns = ent.take::<Cells>().map(|cells| cells.entities);
+        ent.world_scope(|world| {
+            for column in columns.into_iter().flatten() {
+                world.despawn(column);
+            }
+        });
+    }
+
+    emit_children(&mut ent, element, style_override, false);
+    ent
+}
+
+/// Refresh the element put at `path` by its parent, if it is still there.
+fn refresh_element<E: Element + ?Sized>(
+    world: &mut World,
+    path: &str,
+    element: &E,
+    style_override: Option<Styles>,
+) {
+    if let Some(entity) = world.resource::<PathIndex>().get(path) {
+        let mut ent = world.entity_mut(entity);
+        element.refresh(&mut ent, style_override.clone());
+        emit_children(&mut ent, element, style_override, true);
+    }
+}
+
+/// Let `element` put its children below `entity` and remove the ones from
+/// an earlier call that it did not put again. With `refresh` the children
+/// are refreshed rather than put, as during a flush.
+pub(crate) fn emit_children<E: Element + ?Sized>(
+    entity: &mut EntityWorldMut,
+    element: &E,
+    style_override: Option<Styles>,
+    refresh: bool,
+) {
+    // Scratch entities, e.g. those of `Inline`, have no path to put below
+    let Some(path) = entity.get::<Path>().map(|path| path.0.clone()) else {
+        return;
+    };
+    let previous = entity
+        .take::<EmittedChildren>()
+        .map(|emitted| emitted.segments)
+        .unwrap_or_default();
+
+    let mut children = Children {
+        entity,
+        path,
+        style_override,
+        refresh,
+        order: ChildOrder::default(),
+        segments: Vec::new(),
+    };
+    element.children(&mut children);
+
+    let Children {
+        entity,
+        path,
+        order,
+        segments,
+        ..
+    } = children;
+    if previous.is_empty() && segments.is_empty() {
+        return;
+    }
+
+    entity.world_scope(|world| {
+        let current = segments.iter().collect::<HashSet<_>>();
+        for stale in previous.iter().filter(|seg| !current.contains(seg)) {
+            despawn(world, |index| index.remove(&format!("{path}/{stale}")));
+        }
+
+        if order == ChildOrder::Emitted && !refresh {
+            world.resource_mut::<PathIndex>().reorder(&path, &segments);
+        }
+    });
+
+    if !segments.is_empty() {
+        entity.insert(EmittedChildren { segments });
+    }
+}
@@ -0,0 +1,67 @@
+use std::{
+    fmt,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+use bevy_ecs::prelude::*;
+use parking_lot::Mutex;
+
+/// Source of the current time for timers, rates and plain mode intervals.
+pub trait Clock: fmt::Debug + Send + Sync {
+    fn now(&self) -> Instant;
+}
+
+/// Reads the system's monotonic clock.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        Instant::now()
+    }
+}
+
+/// Clock that only moves when told to, for deterministic output in tests.
+/// Clones share the same time.
+#[derive(Debug, Clone)]
+pub struct ManualClock(Arc<Mutex<Instant>>);
+
+impl Default for ManualClock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ManualClock {
+    pub fn new() -> Self {
+        Self(Arc::new(Mutex::new(Instant::now())))
+    }
+
+    pub fn advance(&self, duration: Duration) {
+        *self.0.lock() += duration;
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        *self.0.lock()
+    }
+}
+
+#[derive(Resource, Debug, Clone)]
+pub(crate) struct ClockResource(pub Arc<dyn Clock>);
+
+impl Default for ClockResource {
+    fn default() -> Self {
+        Self(Arc::new(SystemClock))
+    }
+}
+
+/// Current time according to the clock of the [`Nesti`](crate::Nesti) that
+/// owns `world`.
+pub(crate) fn now(world: &World) -> Instant {
+    world
+        .get_resource::<ClockResource>()
+        .map_or_else(Instant::now, |clock| clock.0.now())
+}
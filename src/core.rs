@@ -1,45 +1,267 @@
 use std::{
-    io::{stdout, Write},
-    mem::take,
+    any::{type_name, Any},
+    collections::{HashMap, HashSet},
+    fmt,
+    fs::File,
+    io::{stderr, stdout, IsTerminal, Stderr, Stdout, Write},
     ops::Deref,
-    sync::atomic::{AtomicUsize, Ordering},
+    sync::Arc,
+    time::{Duration, Instant},
 };
 
 use bevy_ecs::prelude::*;
 use crossterm::{
     cursor::{MoveToColumn, MoveUp},
     style::Print,
-    terminal::{Clear, ClearType},
+    terminal::{self, Clear, ClearType},
     QueueableCommand,
 };
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
 use stanza::{
-    renderer::{
-        console::{Console, Decor},
-        Renderer,
-    },
-    style::{HAlign, MinWidth, Styles as StanzaStyles},
+    renderer::{console::Console, Renderer},
+    style::{Bold, HAlign, MinWidth, Styles as StanzaStyles},
     table::{Cell, Col, Content as StanzaContent, Row, Table},
 };
 
-use crate::style::Styles;
+use unicode_width::{UnicodeWidthChar, UnicodeWidthStr};
 
-const LINE_SPACE: &str = "   ";
-const LINE_VERTICAL: &str = "│";
-const LINE_CORNER: &str = "╰─ ";
-const LINE_JUNCTION: &str = "├─ ";
+use crate::{
+    ansi::{sgr, ColorSupport},
+    children::{despawn, emit_children, put_element, Children},
+    clock::{self, Clock, ClockResource},
+    elements::{Cells, DefaultNumberFormat, NumberFormat, NumericValue, TimerState, Unit},
+    index::{normalize, Node, PathIndex},
+    layout::{Glyphs, RenderConfig},
+    pattern::PathPattern,
+    style::Styles,
+    theme::{Role, Theme},
+};
 
-#[derive(Debug, Default)]
+const DEFAULT_PLAIN_INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Debug)]
 pub struct Nesti {
     world: RwLock<World>,
+    writer: Mutex<Writer>,
+    mode: RwLock<RenderMode>,
+    plain_output: RwLock<PlainOutput>,
+    plain_interval: RwLock<Duration>,
+    last_plain: Mutex<Option<PlainFrame>>,
+    viewport: RwLock<Viewport>,
+    columns: RwLock<Vec<Column>>,
+    render_config: RwLock<RenderConfig>,
+    theme: RwLock<Theme>,
     last_line_count: RwLock<usize>,
-    insertion_counter: AtomicUsize,
+}
+
+/// How the tree is written to the output target.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum RenderMode {
+    /// Interactive when the target is a terminal, plain otherwise.
+    #[default]
+    Auto,
+    /// Redraw the tree in place using cursor movement and colour.
+    Interactive,
+    /// Append-only output without escape sequences, for CI and log files.
+    Plain,
+}
+
+/// What plain mode appends on each interval.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PlainOutput {
+    /// The whole tree, whenever anything in it changed.
+    #[default]
+    Snapshot,
+    /// Only the rows whose values differ from the previous snapshot, as
+    /// `path: values`, and `path (removed)` for rows that are gone.
+    Changes,
+}
+
+/// Options for [`Nesti::render_to_string`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RenderOptions {
+    /// Leave out colours and other ANSI escape sequences.
+    pub strip_ansi: bool,
+    /// Draw connectors with [`Glyphs::ASCII`] whatever the
+    /// [`RenderConfig`] says.
+    pub ascii: bool,
+    /// Fit rows into this many columns, truncating labels and values.
+    pub width: Option<usize>,
+    /// Clip the tree to this many rows, following the scroll and focus.
+    pub height: Option<usize>,
+    /// Colours the target can show, others are downgraded to the nearest
+    /// one it has. Drawing to a terminal detects this with
+    /// [`ColorSupport::detect`].
+    pub color_support: ColorSupport,
+}
+
+impl RenderOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Plain ASCII output, suited to snapshot tests and logs.
+    pub fn plain() -> Self {
+        Self::new().strip_ansi().ascii()
+    }
+
+    pub fn strip_ansi(mut self) -> Self {
+        self.strip_ansi = true;
+        self
+    }
+
+    pub fn ascii(mut self) -> Self {
+        self.ascii = true;
+        self
+    }
+
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = Some(width);
+        self
+    }
+
+    pub fn height(mut self, height: usize) -> Self {
+        self.height = Some(height);
+        self
+    }
+
+    pub fn color_support(mut self, support: ColorSupport) -> Self {
+        self.color_support = support;
+        self
+    }
+}
+
+/// Layout of a value column, see [`Nesti::set_columns`].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Column {
+    /// Shown above the column. The header row is left out when no column
+    /// has one.
+    pub header: Option<String>,
+    pub align: Align,
+    pub min_width: usize,
+    /// Longer values are cut short with an ellipsis.
+    pub max_width: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Align {
+    Left,
+    Center,
+    #[default]
+    Right,
+}
+
+impl Column {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn header<H>(mut self, header: H) -> Self
+    where
+        H: Into<String>,
+    {
+        self.header = Some(header.into());
+        self
+    }
+
+    pub fn align(mut self, align: Align) -> Self {
+        self.align = align;
+        self
+    }
+
+    pub fn min_width(mut self, width: usize) -> Self {
+        self.min_width = width;
+        self
+    }
+
+    pub fn max_width(mut self, width: usize) -> Self {
+        self.max_width = Some(width);
+        self
+    }
+
+    /// Stanza styles of the column, widened by the `overhead` of escape
+    /// sequences in its cells.
+    fn styles(&self, overhead: usize) -> StanzaStyles {
+        let align = match self.align {
+            Align::Left => HAlign::Left,
+            Align::Center => HAlign::Centred,
+            Align::Right => HAlign::Right,
+        };
+        StanzaStyles::default()
+            .with(align)
+            .with(MinWidth(self.min_width + overhead))
+    }
+}
+
+/// Last snapshot appended in plain mode.
+#[derive(Debug)]
+struct PlainFrame {
+    at: Instant,
+    content: String,
+    /// Path and values of each row, see [`Nesti::plain_rows`].
+    rows: Vec<(String, String)>,
+}
+
+/// Which rows are shown when the tree is taller than the terminal.
+#[derive(Debug, Default)]
+struct Viewport {
+    offset: usize,
+    focus: Option<String>,
+}
+
+/// Output target the tree is flushed to, defaults to stdout.
+struct Writer {
+    out: Box<dyn Write + Send>,
+    is_terminal: bool,
+}
+
+impl Writer {
+    fn new<W>(writer: W) -> Self
+    where
+        W: Write + Send + 'static,
+    {
+        // Only std handles and files can be probed, anything else is treated
+        // as a non-terminal target.
+        let any = &writer as &dyn Any;
+        let is_terminal = if let Some(w) = any.downcast_ref::<Stdout>() {
+            w.is_terminal()
+        } else if let Some(w) = any.downcast_ref::<Stderr>() {
+            w.is_terminal()
+        } else if let Some(w) = any.downcast_ref::<File>() {
+            w.is_terminal()
+        } else {
+            false
+        };
+
+        Self {
+            out: Box::new(writer),
+            is_terminal,
+        }
+    }
+}
+
+impl Default for Writer {
+    fn default() -> Self {
+        Self::new(stdout())
+    }
+}
+
+impl fmt::Debug for Writer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("Writer")
+    }
 }
 
 #[derive(Component)]
 #[component(storage = "SparseSet")]
 pub struct Content(pub String);
 
+/// Shown in the theme's error style in place of the content when an element could not be
+/// applied, e.g. a delta that overflowed. Cleared by the next put.
+#[derive(Component, Debug, Clone)]
+#[component(storage = "SparseSet")]
+pub struct ErrorMessage(pub String);
+
 #[derive(Component, Default)]
 #[component(storage = "SparseSet")]
 pub struct Style(pub Styles);
@@ -48,192 +270,609 @@ pub struct Style(pub Styles);
 #[component(storage = "SparseSet")]
 pub struct ElementComponent(pub Box<dyn Element + Send + Sync>);
 
+/// Type name of the element last put at a path, mutations aside.
+#[derive(Component, Debug, Clone, Copy)]
+#[component(storage = "SparseSet")]
+pub struct Kind(pub &'static str);
+
+/// Point-in-time copy of what is stored at a path, see [`Nesti::get`].
+#[derive(Debug, Clone)]
+pub struct Snapshot {
+    pub path: String,
+    /// Text shown in the value column.
+    pub content: Option<String>,
+    /// Text shown in the value columns after the first, e.g. by a tuple.
+    pub cells: Vec<String>,
+    /// Raw number behind numeric elements.
+    pub value: Option<NumericValue>,
+    /// Unit written after the number, see [`Unit`].
+    pub unit: Option<String>,
+    pub style: Option<Styles>,
+    /// Role the style is taken from, with a fixed style on top of it.
+    pub role: Option<Role>,
+    /// Type name of the element as given by [`std::any::type_name`].
+    pub kind: Option<&'static str>,
+    pub timer: Option<TimerState>,
+    /// Set when the last put at the path failed, see [`ErrorMessage`].
+    pub error: Option<String>,
+}
+
 pub trait Element {
-    fn spawn(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>);
+    /// Set the content and style of the element's own row. Elements that
+    /// only put [`children`](Element::children) can leave it empty.
+    fn spawn(&self, _entity: &mut EntityWorldMut, _style_override: Option<Styles>) {}
 
     fn tick(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
         self.spawn(entity, style_override);
     }
+
+    /// Called on every flush for the element last put at a path. Elements
+    /// whose content only changes when put again can skip the work here.
+    fn refresh(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
+        self.tick(entity, style_override);
+    }
+
+    /// Mutations such as [`IntegerDelta`](crate::IntegerDelta) are applied
+    /// once when put and are not kept, so the element that was at the path
+    /// before carries on rendering the result.
+    fn is_mutation(&self) -> bool {
+        false
+    }
+
+    /// Put rows below the element's own path, e.g. one per item of a list.
+    /// Called after every spawn, tick and refresh; children that are not
+    /// put again are removed.
+    fn children(&self, _children: &mut Children) {}
+}
+
+impl<T: Element + ?Sized> Element for &T {
+    fn spawn(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
+        (**self).spawn(entity, style_override);
+    }
+
+    fn tick(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
+        (**self).tick(entity, style_override);
+    }
+
+    fn refresh(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
+        (**self).refresh(entity, style_override);
+    }
+
+    fn is_mutation(&self) -> bool {
+        (**self).is_mutation()
+    }
+
+    fn children(&self, children: &mut Children) {
+        (**self).children(children);
+    }
 }
 
 // Dummy element used temporarily during ticking
 struct DummyElement;
-impl Element for DummyElement {
-    fn spawn(&self, _entity: &mut EntityWorldMut, _style_override: Option<Styles>) {}
+impl Element for DummyElement {}
+
+impl Default for Nesti {
+    fn default() -> Self {
+        let mut world = World::new();
+        world.init_resource::<PathIndex>();
+        world.init_resource::<ClockResource>();
+        world.init_resource::<DefaultNumberFormat>();
+
+        Self {
+            world: RwLock::new(world),
+            writer: Mutex::default(),
+            mode: RwLock::default(),
+            plain_output: RwLock::default(),
+            plain_interval: RwLock::new(DEFAULT_PLAIN_INTERVAL),
+            last_plain: Mutex::default(),
+            viewport: RwLock::default(),
+            columns: RwLock::default(),
+            render_config: RwLock::default(),
+            theme: RwLock::default(),
+            last_line_count: RwLock::default(),
+        }
+    }
 }
 
 impl Nesti {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Convenience for sending the tree to stderr, leaving stdout for data.
+    pub fn stderr() -> Self {
+        Self::with_writer(stderr())
+    }
+
+    /// Create an instance that flushes to the given writer instead of stdout,
+    /// e.g. `stderr()` or a `File`. The writer is owned from here on, use a
+    /// [`SharedBuffer`](crate::testing::SharedBuffer) to read the output back.
+    pub fn with_writer<W>(writer: W) -> Self
+    where
+        W: Write + Send + 'static,
+    {
+        let nesti = Self::default();
+        nesti.set_writer(writer);
+        nesti
+    }
+
+    /// Replace the output target. The next flush starts a fresh frame on the
+    /// new writer rather than moving the cursor over the previous output.
+    ///
+    /// Stdout, stderr and files are probed for a terminal when the mode is
+    /// [`RenderMode::Auto`], any other writer is treated as plain.
+    pub fn set_writer<W>(&self, writer: W)
+    where
+        W: Write + Send + 'static,
+    {
+        *self.writer.lock() = Writer::new(writer);
+        *self.last_line_count.write() = 0;
+        *self.last_plain.lock() = None;
+    }
+
+    /// Create an instance whose timers and rates read time from `clock`, e.g. a
+    /// [`ManualClock`](crate::ManualClock) in tests.
+    pub fn with_clock<C>(clock: C) -> Self
+    where
+        C: Clock + 'static,
+    {
+        let nesti = Self::default();
+        nesti.set_clock(clock);
+        nesti
+    }
+
+    /// Replace the clock. Timers already running keep their start time, so
+    /// switching between unrelated clocks makes their elapsed time jump.
+    pub fn set_clock<C>(&self, clock: C)
+    where
+        C: Clock + 'static,
+    {
+        self.world
+            .write()
+            .insert_resource(ClockResource(Arc::new(clock)));
+    }
+
+    /// Format used for numbers that were not given their own, applied to
+    /// numbers already in the tree on the next flush.
+    pub fn set_number_format(&self, format: NumberFormat) {
+        self.world
+            .write()
+            .insert_resource(DefaultNumberFormat(format));
+    }
+
+    pub fn set_mode(&self, mode: RenderMode) {
+        *self.mode.write() = mode;
+        *self.last_line_count.write() = 0;
+        *self.last_plain.lock() = None;
+    }
+
+    pub fn set_plain_output(&self, output: PlainOutput) {
+        *self.plain_output.write() = output;
+    }
+
+    /// Minimum time between two appends in plain mode.
+    pub fn set_plain_interval(&self, interval: Duration) {
+        *self.plain_interval.write() = interval;
+    }
+
+    /// Scroll the tree so the first visible row is `offset` rows down, used
+    /// when the tree is taller than the terminal.
+    pub fn set_scroll(&self, offset: usize) {
+        self.viewport.write().offset = offset;
+    }
+
+    /// Keep the row at `path` on screen when the tree is taller than the
+    /// terminal, scrolling as little as possible to reach it.
+    pub fn set_focus<P>(&self, path: P)
+    where
+        P: Into<String>,
+    {
+        self.viewport.write().focus = Some(path.into());
+    }
+
+    pub fn clear_focus(&self) {
+        self.viewport.write().focus = None;
+    }
+
+    /// Replace the glyphs, indent, widths, padding and borders used to draw
+    /// the tree, e.g. `RenderConfig::new().glyphs(Glyphs::HEAVY)`.
+    pub fn set_render_config(&self, config: RenderConfig) {
+        *self.render_config.write() = config;
+    }
+
+    /// Replace the styles of the [`Role`]s, including those of rows already
+    /// in the tree, e.g. `nesti.set_theme(Theme::light())`.
+    pub fn set_theme(&self, theme: Theme) {
+        *self.theme.write() = theme;
+    }
+
+    /// Lay out the value columns, the first entry describing the column
+    /// next to the labels. Rows with more values than configured columns
+    /// use the defaults for the rest.
+    ///
+    /// ```
+    /// # use nesti::{Align, Column, Nesti, Text};
+    /// let nesti = Nesti::new();
+    /// nesti.set_columns([
+    ///     Column::new().header("status"),
+    ///     Column::new().header("eta").min_width(8).align(Align::Left),
+    /// ]);
+    /// nesti.put("jobs/build", (Text("running"), Text("2m")));
+    /// ```
+    pub fn set_columns<I>(&self, columns: I)
+    where
+        I: IntoIterator<Item = Column>,
+    {
+        *self.columns.write() = columns.into_iter().collect();
+    }
+
     pub fn put<P, E>(&self, path: P, element: E)
     where
         P: Into<String>,
         E: Element + Send + Sync + 'static,
     {
-        let path = path.into();
+        let path = normalize(&path.into());
         let mut world = self.world.write();
         let element_ptr = Box::new(element) as Box<dyn Element + Send + Sync>;
 
-        let entity = {
-            let mut query = world.query::<(Entity, &Path)>();
-            query
-                .iter(&world)
-                .find(|(_, p)| &p.0 == &path)
-                .map(|(e, _)| e)
-        };
-
-        if let Some(entity) = entity {
-            // Entity already exists at path
-            let mut ent = world.entity_mut(entity);
-            element_ptr.tick(&mut ent, None);
-
-            // Store the element instance for future ticking
-            ent.insert(ElementComponent(element_ptr));
-        } else {
-            let insertion_order = self.insertion_counter.fetch_add(1, Ordering::SeqCst);
-            let mut ent = world.spawn((Path(path), InsertionOrder(insertion_order)));
-            element_ptr.spawn(&mut ent, None);
+        let mut ent = put_element(&mut world, &path, element_ptr.as_ref(), None);
 
-            // Store the element instance for future ticking
-            ent.insert(ElementComponent(element_ptr));
+        // Store the element instance for future ticking
+        if !element_ptr.is_mutation() {
+            ent.insert((ElementComponent(element_ptr), Kind(type_name::<E>())));
         }
     }
 
+    /// Remove the node at `path` and everything below it. Matching is done on
+    /// whole segments, so popping `jobs/1` leaves `jobs/10` alone.
     pub fn pop(&self, path: &str) {
-        let mut world = self.world.write();
-        let mut cmds = world.commands();
-        let path = path.to_owned();
-
-        cmds.queue({
-            let path = path.to_owned();
-            move |world: &mut World| {
-                let entities: Vec<Entity> = {
-                    let mut q = world.query::<(Entity, &Path)>();
-                    q.iter(world)
-                        .filter(|(_, p)| p.starts_with(&path))
-                        .map(|(e, _)| e)
+        self.despawn(|index| index.remove(path));
+    }
+
+    /// Remove only the node at `path`, its children stay in the tree.
+    pub fn pop_exact(&self, path: &str) {
+        self.despawn(|index| index.remove_exact(path));
+    }
+
+    /// Remove everything below `path` but keep the node itself.
+    pub fn pop_children(&self, path: &str) {
+        self.despawn(|index| index.remove_children(path));
+    }
+
+    /// Remove every node matching `pattern`, e.g. `system/timers/*`, along
+    /// with everything below it.
+    pub fn pop_matching<P>(&self, pattern: P)
+    where
+        P: Into<PathPattern>,
+    {
+        let pattern = pattern.into();
+        self.despawn(|index| index.remove_matching(&pattern));
+    }
+
+    /// Paths holding a value that match `pattern`, in tree order.
+    pub fn select<P>(&self, pattern: P) -> impl Iterator<Item = String>
+    where
+        P: Into<PathPattern>,
+    {
+        let pattern = pattern.into();
+        let matched = self.world.read().resource::<PathIndex>().matching(&pattern);
+        matched.into_iter()
+    }
+
+    /// Read back what is currently stored at `path`, if anything.
+    pub fn get(&self, path: &str) -> Option<Snapshot> {
+        let world = self.world.read();
+        let entity = world.resource::<PathIndex>().get(path)?;
+        let entity = world.entity(entity);
+
+        Some(Snapshot {
+            path: normalize(path),
+            content: entity.get::<Content>().map(|c| c.0.clone()),
+            cells: entity
+                .get::<Cells>()
+                .map(|cells| {
+                    cells
+                        .entities
+                        .iter()
+                        .map(|&cell| {
+                            world
+                                .get::<Content>(cell)
+                                .map_or_else(String::new, |c| c.0.clone())
+                        })
                         .collect()
-                };
+                })
+                .unwrap_or_default(),
+            value: entity.get::<NumericValue>().cloned(),
+            unit: entity.get::<Unit>().map(|u| u.0.clone()),
+            style: entity.get::<Style>().map(|s| s.0.clone()),
+            role: entity.get::<Role>().copied(),
+            kind: entity.get::<Kind>().map(|k| k.0),
+            timer: TimerState::of(&entity, clock::now(&world)),
+            error: entity.get::<ErrorMessage>().map(|e| e.0.clone()),
+        })
+    }
 
-                for entity in entities {
-                    world.despawn(entity);
-                }
-            }
-        });
+    /// Paths directly below `path`, including intermediate nodes that only
+    /// exist because something deeper was put.
+    pub fn children(&self, path: &str) -> impl Iterator<Item = String> {
+        let world = self.world.read();
+        let parent = normalize(path);
+        let children = world
+            .resource::<PathIndex>()
+            .node(&parent)
+            .map(|node| {
+                node.children()
+                    .into_iter()
+                    .map(|(seg, _)| match parent.is_empty() {
+                        true => seg.to_owned(),
+                        false => format!("{parent}/{seg}"),
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+
+        children.into_iter()
+    }
+
+    /// Every path holding a value, in tree order.
+    pub fn paths(&self) -> impl Iterator<Item = String> {
+        self.select("**")
+    }
+
+    fn despawn(&self, remove: impl FnOnce(&mut PathIndex) -> Vec<Entity>) {
+        despawn(&mut self.world.write(), remove);
+    }
+
+    /// Render the tree as it would be drawn by [`Nesti::flush`], without
+    /// writing it anywhere or moving the cursor.
+    pub fn render_to_string(&self, options: RenderOptions) -> String {
+        let mut world = self.world.write();
+        tick(&mut world);
+        self.render(&world, options)
     }
 
     pub fn flush(&self) -> Result<(), std::io::Error> {
         let mut world = self.world.write();
-        world.flush();
-
-        // Tick all elements that have instances stored
-        // We need to collect entities first to avoid borrowing conflicts
-        let mut entities_to_tick: Vec<(Entity, Box<dyn Element + Send + Sync>)> = Vec::new();
-
-        {
-            let mut query = world.query::<(Entity, &mut ElementComponent)>();
-            for (entity, mut element_instance) in query.iter_mut(&mut world) {
-                // Take ownership of the element temporarily
-                let element = std::mem::replace(&mut element_instance.0, Box::new(DummyElement));
-                entities_to_tick.push((entity, element));
-            }
-        }
+        tick(&mut world);
 
-        // Now tick each element and put them back
-        for (entity, element) in entities_to_tick {
-            let mut ent = world.entity_mut(entity);
-            element.tick(&mut ent, None);
+        let mut writer = self.writer.lock();
+        let interactive = match *self.mode.read() {
+            RenderMode::Auto => writer.is_terminal,
+            RenderMode::Interactive => true,
+            RenderMode::Plain => false,
+        };
 
-            // Put the element back
-            ent.insert(ElementComponent(element));
+        if !interactive {
+            let content = self.render(&world, RenderOptions::new().strip_ansi());
+            let rows = self.plain_rows(&world);
+            return self.write_plain(&mut writer.out, content, rows, clock::now(&world));
         }
 
-        let content = self.render(&mut world);
+        let mut options = RenderOptions::new().color_support(ColorSupport::detect());
+        if let Ok((width, height)) = terminal::size() {
+            options = options.width(width.into()).height(height.into());
+        }
+        let content = self.render(&world, options);
 
-        let new_line_count = content.lines().count();
+        let new_line_count = physical_rows(&content, options.width);
         let mut guard = self.last_line_count.write();
         let last_line_count = *guard;
 
-        let mut stdout = stdout();
+        let out = &mut writer.out;
 
         if last_line_count > 0 {
-            stdout.queue(MoveToColumn(0))?;
-            stdout.queue(MoveUp(last_line_count as u16))?;
+            out.queue(MoveToColumn(0))?;
+            out.queue(MoveUp(last_line_count as u16))?;
         }
 
-        stdout.queue(Clear(ClearType::FromCursorDown))?;
-        stdout.queue(Print(content))?;
-        stdout.flush()?;
+        out.queue(Clear(ClearType::FromCursorDown))?;
+        out.queue(Print(content))?;
+        out.flush()?;
 
         *guard = new_line_count;
 
         Ok(())
     }
 
-    fn render(&self, world: &mut World) -> String {
-        let mut q = world.query::<(&Path, &Content, Option<&Style>, &InsertionOrder)>();
-
-        let mut rows: Vec<(Vec<&str>, &Content, Option<&Style>, usize)> = q
-            .iter(&world)
-            .map(|(path, c, s, order)| {
-                (
-                    path.split('/')
-                        .filter(|s| !s.is_empty())
-                        .collect::<Vec<_>>(),
-                    c,
-                    s,
-                    order.0,
-                )
+    fn write_plain(
+        &self,
+        out: &mut dyn Write,
+        content: String,
+        rows: Vec<(String, String)>,
+        now: Instant,
+    ) -> Result<(), std::io::Error> {
+        let mut last = self.last_plain.lock();
+
+        let content = content
+            .lines()
+            .map(str::trim_end)
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let previous = match last.as_ref() {
+            Some(frame) if now.duration_since(frame.at) < *self.plain_interval.read() => {
+                return Ok(());
+            }
+            Some(frame) if frame.content == content => return Ok(()),
+            Some(frame) => Some(&frame.rows),
+            None => None,
+        };
+
+        match (*self.plain_output.read(), previous) {
+            (PlainOutput::Changes, Some(previous)) => {
+                // Compare rows by path, so inserting a row does not report
+                // every row after it
+                let before = previous.iter().cloned().collect::<HashMap<_, _>>();
+                for (path, values) in &rows {
+                    if before.get(path) != Some(values) {
+                        writeln!(out, "{path}: {values}")?;
+                    }
+                }
+
+                let current = rows.iter().map(|(path, _)| path).collect::<HashSet<_>>();
+                for (path, _) in previous.iter().filter(|(path, _)| !current.contains(path)) {
+                    writeln!(out, "{path} (removed)")?;
+                }
+            }
+            _ => writeln!(out, "{content}")?,
+        }
+
+        out.flush()?;
+        *last = Some(PlainFrame {
+            at: now,
+            content,
+            rows,
+        });
+
+        Ok(())
+    }
+
+    /// Path and values of every row with a value, compared by
+    /// [`PlainOutput::Changes`].
+    fn plain_rows(&self, world: &World) -> Vec<(String, String)> {
+        let config = self.render_config.read();
+        let mut lines = Vec::new();
+        render_node(
+            world,
+            world.resource::<PathIndex>().root(),
+            0,
+            &config,
+            &mut String::new(),
+            &mut String::new(),
+            &mut lines,
+        );
+
+        lines
+            .into_iter()
+            .filter(|line| line.values.iter().any(|(value, _)| !value.is_empty()))
+            .map(|line| {
+                let values = line.values.into_iter().map(|(value, _)| value);
+                (line.path, values.collect::<Vec<_>>().join(" "))
             })
-            .collect();
+            .collect()
+    }
 
-        rows.sort_unstable_by(|a, b| a.3.cmp(&b.3));
+    fn render(&self, world: &World, options: RenderOptions) -> String {
+        let color = !options.strip_ansi && options.color_support != ColorSupport::None;
+        let mut config = self.render_config.read().clone();
+        if options.ascii {
+            config.glyphs = Glyphs::ASCII;
+        }
+        let glyphs = &config.glyphs;
 
+        let mut path = String::new();
         let mut prefix = String::new();
-        let mut buffer = String::new();
-        let mut table_rows = Vec::with_capacity(rows.len());
+        let mut lines = Vec::new();
 
-        render_range(
-            &rows,
-            0,
-            rows.len(),
+        render_node(
+            world,
+            world.resource::<PathIndex>().root(),
             0,
+            &config,
+            &mut path,
             &mut prefix,
-            &mut table_rows,
-            &mut buffer,
+            &mut lines,
         );
 
-        if table_rows.is_empty() {
+        if lines.is_empty() {
             return String::new();
         }
 
-        let table = Table::default()
-            .with_cols(vec![
-                Col::new(
-                    StanzaStyles::default()
-                        .with(HAlign::Left)
-                        .with(MinWidth(30)),
-                ),
-                Col::new(StanzaStyles::default().with(HAlign::Right)),
-            ])
-            .with_rows(table_rows);
-
-        let renderer = Console(Decor {
-            up_thin_down_thin: ' ',
-            draw_outer_border: false,
-            draw_inner_horizontal_border: false,
-            ..Decor::default()
-        });
+        let columns = self.columns.read();
+        let header = Line::header(&columns);
+
+        if let Some(height) = options.height {
+            // Leave room for the blank lines around the table and the row the
+            // cursor rests on, otherwise the top scrolls out of reach.
+            let reserved =
+                config.padding_top + config.padding_bottom + 2 + usize::from(header.is_some());
+            let mut viewport = self.viewport.write();
+            clip_lines(
+                &mut lines,
+                height.saturating_sub(reserved),
+                &mut viewport,
+                &config,
+            );
+        }
+
+        if let Some(header) = header {
+            lines.insert(0, header);
+        }
+
+        for line in lines.iter_mut() {
+            line.limit(&columns, glyphs);
+        }
+        let label_width = fit_lines(&mut lines, options.width, &columns, &config);
+
+        let theme = self.theme.read();
+        let paint = color.then(|| Paint::new(&theme, options.color_support, &lines));
+        let overhead = paint.as_ref().map_or(0, Paint::overhead);
+
+        let value_cols = lines
+            .iter()
+            .map(|line| line.values.len())
+            .max()
+            .unwrap_or(0);
+        let cols = std::iter::once(Col::new(StanzaStyles::default().with(HAlign::Left)))
+            .chain((0..value_cols).map(|i| {
+                let column = columns.get(i).cloned().unwrap_or_default();
+                Col::new(column.styles(overhead))
+            }))
+            .collect();
+
+        let table = Table::default().with_cols(cols).with_rows(
+            lines
+                .into_iter()
+                .map(|line| line.into_row(label_width, paint.as_ref())),
+        );
+
+        let mut decor = config.decor.clone();
+        decor.print_escape_codes = color;
+        let renderer = Console(decor);
 
-        format!("\n{}\n", renderer.render(&table))
+        format!(
+            "{}{}\n{}",
+            "\n".repeat(config.padding_top),
+            renderer.render(&table).replace(FILLER, ""),
+            "\n".repeat(config.padding_bottom)
+        )
     }
 }
 
-#[derive(Component, PartialEq, Eq)]
-#[component(storage = "SparseSet")]
-struct Path(pub(crate) String);
+/// Tick every stored element so time based content is current.
+fn tick(world: &mut World) {
+    world.flush();
 
-#[derive(Component)]
+    // Tick all elements that have instances stored
+    // We need to collect entities first to avoid borrowing conflicts
+    let mut entities_to_tick: Vec<(Entity, Box<dyn Element + Send + Sync>)> = Vec::new();
+
+    {
+        let mut query = world.query::<(Entity, &mut ElementComponent)>();
+        for (entity, mut element_instance) in query.iter_mut(world) {
+            // Take ownership of the element temporarily
+            let element = std::mem::replace(&mut element_instance.0, Box::new(DummyElement));
+            entities_to_tick.push((entity, element));
+        }
+    }
+
+    // Now tick each element and put them back, skipping any that an earlier
+    // element removed along with its children
+    for (entity, element) in entities_to_tick {
+        let Ok(mut ent) = world.get_entity_mut(entity) else {
+            continue;
+        };
+        element.refresh(&mut ent, None);
+        emit_children(&mut ent, element.as_ref(), None, true);
+
+        // Put the element back
+        ent.insert(ElementComponent(element));
+    }
+}
+
+#[derive(Component, PartialEq, Eq)]
 #[component(storage = "SparseSet")]
-struct InsertionOrder(pub usize);
+pub(crate) struct Path(pub(crate) String);
 
 impl Deref for Path {
     type Target = String;
@@ -243,90 +882,500 @@ impl Deref for Path {
     }
 }
 
-fn render_range(
-    rows: &[(Vec<&str>, &Content, Option<&Style>, usize)],
-    start: usize,
-    end: usize,
-    depth: usize,
-    prefix: &mut String,
-    out: &mut Vec<Row>,
-    buffer: &mut String,
-) {
-    let mut i = start;
-    while i < end {
-        if rows[i].0.len() <= depth {
-            i += 1;
-            continue;
-        }
+/// A single tree row before it is laid out into the table.
+struct Line {
+    path: String,
+    guide: String,
+    label: String,
+    /// One entry per value column, blank ones included.
+    values: Vec<(String, CellStyle)>,
+}
 
-        let seg = rows[i].0[depth];
+/// Style of a value cell, roles are looked up in the theme once the row is
+/// drawn.
+enum CellStyle {
+    Fixed(StanzaStyles),
+    Role(Role),
+    /// The role's style with fixed styles such as a background on top.
+    Themed(Role, StanzaStyles),
+}
 
-        let mut j = i + 1;
-        while j < end
-            && rows[j].0.len() > depth
-            && rows[j].0[..depth] == rows[i].0[..depth]
-            && rows[j].0[depth] == seg
-        {
-            j += 1;
+impl Line {
+    fn marker(hidden: usize, config: &RenderConfig) -> Self {
+        let indent = " ".repeat(config.indent);
+        Self {
+            path: String::new(),
+            guide: format!("{indent}{} {hidden} more rows", config.glyphs.ellipsis),
+            label: String::new(),
+            values: Vec::new(),
         }
+    }
+
+    /// Row of column headers, if any column has one.
+    fn header(columns: &[Column]) -> Option<Self> {
+        columns
+            .iter()
+            .any(|col| col.header.is_some())
+            .then(|| Self {
+                path: String::new(),
+                guide: String::new(),
+                label: String::new(),
+                values: columns
+                    .iter()
+                    .map(|col| {
+                        let header = col.header.clone().unwrap_or_default();
+                        let style = StanzaStyles::default().with(Bold(true));
+                        (header, CellStyle::Fixed(style))
+                    })
+                    .collect(),
+            })
+    }
 
-        let mut k = j;
-        while k < end && rows[k].0.len() <= depth {
-            k += 1;
+    fn label_width(&self) -> usize {
+        self.guide.width() + self.label.width()
+    }
+
+    fn value_width(&self, col: usize) -> usize {
+        self.values.get(col).map_or(0, |(v, _)| v.width())
+    }
+
+    /// Cut values down to the maximum width of their column.
+    fn limit(&mut self, columns: &[Column], glyphs: &Glyphs) {
+        for ((value, _), col) in self.values.iter_mut().zip(columns) {
+            if let Some(max_width) = col.max_width {
+                ellipsize(value, max_width, glyphs);
+            }
         }
-        let is_last = k == end || rows[k].0[..depth] != rows[i].0[..depth];
+    }
 
-        let conn = if depth == 0 {
-            ""
-        } else if is_last {
-            LINE_CORNER
+    fn truncate(&mut self, label_width: usize, value_width: usize, glyphs: &Glyphs) {
+        let guide_width = self.guide.width();
+        if guide_width >= label_width {
+            ellipsize(&mut self.guide, label_width, glyphs);
+            self.label.clear();
         } else {
-            LINE_JUNCTION
-        };
+            ellipsize(&mut self.label, label_width - guide_width, glyphs);
+        }
 
-        buffer.clear();
-        buffer.push_str("\x1b[90m");
-        buffer.push_str("   ");
-        buffer.push_str(prefix);
-        buffer.push_str(conn);
-        buffer.push_str("\x1b[0m");
-        buffer.push_str(seg);
+        if let Some((value, _)) = self.values.first_mut() {
+            ellipsize(value, value_width, glyphs);
+        }
+    }
+
+    /// Lay the line out as a table row, coloured with `paint` unless it is
+    /// `None`.
+    fn into_row(self, label_width: usize, paint: Option<&Paint>) -> Row {
+        let mut label = String::with_capacity(label_width + 16);
+        push_styled(
+            &mut label,
+            &self.guide,
+            paint.map(|p| p.label_escape(Role::Connector)),
+        );
+        push_styled(
+            &mut label,
+            &self.label,
+            paint.map(|p| p.label_escape(Role::Label)),
+        );
 
-        let mut cells = Vec::with_capacity(2);
+        // Pad here so every label cell carries the same escape overhead and
+        // stanza's char-based column width matches the visible width.
+        let padding = label_width.saturating_sub(self.label_width());
+        label.extend(std::iter::repeat_n(' ', padding));
+        label.extend(fillers(&self.guide).chain(fillers(&self.label)));
 
+        let mut cells = Vec::with_capacity(1 + self.values.len());
         cells.push(Cell::new(
             StanzaStyles::default(),
-            StanzaContent::Label(take(buffer)),
+            StanzaContent::Label(label),
         ));
 
-        if let Some(idx) = (i..j).find(|&idx| rows[idx].0.len() == depth + 1) {
-            let (_, content, style, _) = &rows[idx];
+        let Some(paint) = paint else {
+            for (mut value, _) in self.values {
+                value.extend(fillers(&value));
+                cells.push(Cell::new(
+                    StanzaStyles::default(),
+                    StanzaContent::Label(value),
+                ));
+            }
+            return Row::new(StanzaStyles::default(), cells);
+        };
+
+        // Blank cells up to the last column, so they carry the same escape
+        // overhead as the rest of their column
+        let blanks = paint.columns.saturating_sub(self.values.len());
+        let values = self.values.into_iter().chain(
+            std::iter::repeat_with(|| (String::new(), CellStyle::Role(Role::Value))).take(blanks),
+        );
+        for (value, style) in values {
+            let mut content = paint.value_escape(&style);
+            content.push_str(&value);
+            content.extend(fillers(&value));
+            content.push_str(RESET);
             cells.push(Cell::new(
-                match style {
-                    Some(s) => s.0 .0.clone(),
-                    None => StanzaStyles::default(),
-                },
-                StanzaContent::Label(content.0.clone()),
+                StanzaStyles::default(),
+                StanzaContent::Label(content),
             ));
         }
 
-        out.push(Row::new(StanzaStyles::default(), cells));
+        Row::new(StanzaStyles::default(), cells)
+    }
+}
+
+const RESET: &str = "\x1b[0m";
+
+/// Colours of a render: the theme, what the target can show and the length
+/// value escapes are padded to.
+struct Paint<'a> {
+    theme: &'a Theme,
+    support: ColorSupport,
+    /// Number of value columns.
+    columns: usize,
+    escape_len: usize,
+}
+
+impl<'a> Paint<'a> {
+    fn new(theme: &'a Theme, support: ColorSupport, lines: &[Line]) -> Self {
+        let mut paint = Self {
+            theme,
+            support,
+            columns: lines
+                .iter()
+                .map(|line| line.values.len())
+                .max()
+                .unwrap_or(0),
+            escape_len: 0,
+        };
+        paint.escape_len = lines
+            .iter()
+            .flat_map(|line| &line.values)
+            .map(|(_, style)| paint.value_escape(style).len())
+            .max()
+            .unwrap_or(0);
+        paint
+    }
+
+    /// Chars a coloured value cell has on top of its text.
+    fn overhead(&self) -> usize {
+        self.escape_len + RESET.len()
+    }
+
+    fn label_escape(&self, role: Role) -> String {
+        match sgr(&self.theme.style(role).0, self.support) {
+            sgr if sgr.is_empty() => sgr,
+            sgr => format!("\x1b[{sgr}m"),
+        }
+    }
+
+    /// Escape in front of a value. Stanza sizes columns by char count, so
+    /// every value escape is padded with leading zeros to the same length
+    /// and starts with a reset, which the zeros leave unchanged.
+    fn value_escape(&self, style: &CellStyle) -> String {
+        let themed;
+        let styles = match style {
+            CellStyle::Fixed(styles) => styles,
+            CellStyle::Role(role) => &self.theme.style(*role).0,
+            CellStyle::Themed(role, styles) => {
+                themed = StanzaStyles::default()
+                    .with_all(&self.theme.style(*role).0)
+                    .with_all(styles);
+                &themed
+            }
+        };
+        let params = match sgr(styles, self.support) {
+            sgr if sgr.is_empty() => "0".to_owned(),
+            sgr => format!("0;{sgr}"),
+        };
+        let zeros = self.escape_len.saturating_sub(params.len() + 3);
+        format!("\x1b[{}{params}m", "0".repeat(zeros))
+    }
+}
+
+/// Append `text` to `out`, wrapped in `escape` and a reset.
+fn push_styled(out: &mut String, text: &str, escape: Option<String>) {
+    let escape = escape.unwrap_or_default();
+    out.push_str(&escape);
+    out.push_str(text);
+    if !escape.is_empty() {
+        out.push_str(RESET);
+    }
+}
+
+/// Limit `lines` to `max_rows`, replacing the rows scrolled out of view with
+/// "… N more rows" markers. The viewport offset is clamped and moved to keep
+/// the focused path visible.
+fn clip_lines(
+    lines: &mut Vec<Line>,
+    max_rows: usize,
+    viewport: &mut Viewport,
+    config: &RenderConfig,
+) {
+    let total = lines.len();
+    if total <= max_rows {
+        return;
+    }
+
+    let focus = viewport
+        .focus
+        .as_deref()
+        .and_then(|focus| lines.iter().position(|line| line.path == focus));
+
+    // Reserve a row for the marker below, and one above once scrolled
+    let window = |offset: usize| {
+        let markers = if offset > 0 { 2 } else { 1 };
+        max_rows.saturating_sub(markers).max(1)
+    };
+
+    let mut offset = viewport.offset;
+    if let Some(focus) = focus {
+        if focus < offset {
+            offset = focus;
+        } else if focus >= offset + window(offset) {
+            offset = focus + 1 - window(1);
+        }
+    }
+
+    let offset = offset.min(total - window(offset));
+    let visible = window(offset);
+    viewport.offset = offset;
+
+    let below = total - offset - visible;
+    lines.truncate(offset + visible);
+    if below > 0 {
+        lines.push(Line::marker(below, config));
+    }
+
+    if offset > 0 {
+        lines.drain(..offset);
+        lines.insert(0, Line::marker(offset, config));
+    }
+}
+
+/// Work out the label column width, shrinking labels and then the first value
+/// column when the rows would otherwise be wider than the terminal and wrap.
+/// Further value columns keep their width.
+fn fit_lines(
+    lines: &mut [Line],
+    width: Option<usize>,
+    columns: &[Column],
+    config: &RenderConfig,
+) -> usize {
+    let label_width = lines
+        .iter()
+        .map(Line::label_width)
+        .max()
+        .unwrap_or(0)
+        .max(config.label_min_width);
+    let value_cols = lines
+        .iter()
+        .map(|line| line.values.len())
+        .max()
+        .unwrap_or(0);
+    let col_width = |col: usize| {
+        let min_width = columns.get(col).map_or(0, |c| c.min_width);
+        lines
+            .iter()
+            .map(|line| line.value_width(col))
+            .max()
+            .unwrap_or(0)
+            .max(min_width)
+    };
+    let value_width = col_width(0);
+    let others = (1..value_cols).map(|col| col_width(col) + 1).sum::<usize>();
+
+    let Some(width) = width else {
+        return label_width;
+    };
+
+    // Columns are separated by a single blank
+    let available = width.saturating_sub(1 + others);
+    if label_width + value_width <= available {
+        return label_width;
+    }
+
+    let label_width = available
+        .saturating_sub(value_width)
+        .max(label_width.min(available / 2));
+    let value_width = available.saturating_sub(label_width);
+
+    for line in lines.iter_mut() {
+        line.truncate(label_width, value_width, &config.glyphs);
+    }
+
+    label_width
+}
+
+/// Shorten `s` to at most `width` columns, marking the cut with an ellipsis.
+/// A wide char that would straddle the limit is cut as a whole.
+fn ellipsize(s: &mut String, width: usize, glyphs: &Glyphs) {
+    if s.width() <= width {
+        return;
+    }
+
+    let ellipsis = glyphs.ellipsis.width();
+    let (keep, ellipsis) = match width >= ellipsis {
+        true => (width - ellipsis, glyphs.ellipsis),
+        false => (width, ""),
+    };
+
+    let mut kept = 0;
+    let cut = s.char_indices().find(|&(_, c)| {
+        kept += c.width().unwrap_or(0);
+        kept > keep
+    });
+    if let Some((idx, _)) = cut {
+        s.truncate(idx);
+    }
+    s.push_str(ellipsis);
+}
+
+/// Unicode noncharacter marking columns stanza has to skip. Stanza sizes
+/// cells by char count, so a cell gets one per column its wide chars take
+/// beyond their char count, and they are dropped from the rendered table.
+const FILLER: char = '\u{FDD0}';
+
+/// `FILLER`s making up for the wide chars of `text`.
+fn fillers(text: &str) -> impl Iterator<Item = char> {
+    std::iter::repeat_n(FILLER, text.width().saturating_sub(text.chars().count()))
+}
+
+/// Number of terminal rows `content` occupies once long lines wrap.
+fn physical_rows(content: &str, width: Option<usize>) -> usize {
+    content
+        .lines()
+        .map(|line| match width {
+            Some(width) if width > 0 => visible_width(line).div_ceil(width).max(1),
+            _ => 1,
+        })
+        .sum()
+}
+
+/// Display width of `line` ignoring ANSI escape sequences.
+fn visible_width(line: &str) -> usize {
+    let mut text = String::with_capacity(line.len());
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            // Skip to the final byte of the CSI sequence
+            for c in chars.by_ref() {
+                if ('@'..='~').contains(&c) && c != '[' {
+                    break;
+                }
+            }
+        } else {
+            text.push(c);
+        }
+    }
+    text.width()
+}
+
+/// Whether `node` or anything below it has content to show.
+fn is_visible(world: &World, node: &Node) -> bool {
+    let has_value = node.entity.is_some_and(|entity| {
+        world.get::<Content>(entity).is_some() || world.get::<ErrorMessage>(entity).is_some()
+    });
+
+    has_value
+        || node
+            .children()
+            .iter()
+            .any(|(_, child)| is_visible(world, child))
+}
+
+/// Text and style shown for `entity` in a value column.
+fn cell_value(world: &World, entity: Entity) -> Option<(String, CellStyle)> {
+    if let Some(error) = world.get::<ErrorMessage>(entity) {
+        return Some((error.0.clone(), CellStyle::Role(Role::Error)));
+    }
+
+    let content = world.get::<Content>(entity)?;
+    let style = match (world.get::<Style>(entity), world.get::<Role>(entity)) {
+        (Some(style), Some(&role)) => CellStyle::Themed(role, style.0 .0.clone()),
+        (Some(style), None) => CellStyle::Fixed(style.0 .0.clone()),
+        (None, Some(&role)) => CellStyle::Role(role),
+        (None, None) => CellStyle::Role(Role::Value),
+    };
+    Some((content.0.clone(), style))
+}
+
+fn render_node(
+    world: &World,
+    node: &Node,
+    depth: usize,
+    config: &RenderConfig,
+    path: &mut String,
+    prefix: &mut String,
+    out: &mut Vec<Line>,
+) {
+    let children = node
+        .children()
+        .into_iter()
+        .filter(|(_, child)| is_visible(world, child))
+        .collect::<Vec<_>>();
+    let count = children.len();
+
+    for (n, (seg, child)) in children.into_iter().enumerate() {
+        let is_last = n + 1 == count;
+
+        let conn = if depth == 0 {
+            ""
+        } else if is_last {
+            config.glyphs.corner
+        } else {
+            config.glyphs.junction
+        };
+
+        let mut guide = String::with_capacity(config.indent + prefix.len() + conn.len());
+        guide.extend(std::iter::repeat_n(' ', config.indent));
+        guide.push_str(prefix);
+        guide.push_str(conn);
+
+        let mut values = Vec::new();
+        if let Some(entity) = child.entity {
+            values.extend(cell_value(world, entity));
+
+            // Blank first cell when only the extra columns have a value
+            let cells = world.get::<Cells>(entity).map_or(&[][..], |c| &c.entities);
+            if !cells.is_empty() && values.is_empty() {
+                values.push((String::new(), CellStyle::Role(Role::Value)));
+            }
+            for &cell in cells {
+                values.push(
+                    cell_value(world, cell)
+                        .unwrap_or_else(|| (String::new(), CellStyle::Role(Role::Value))),
+                );
+            }
+
+            // Trailing blanks would pad the row for nothing
+            while values.last().is_some_and(|(v, _)| v.is_empty()) {
+                values.pop();
+            }
+        }
+
+        let saved_path = path.len();
+        if depth != 0 {
+            path.push('/');
+        }
+        path.push_str(seg);
+
+        out.push(Line {
+            path: path.clone(),
+            guide,
+            label: seg.to_owned(),
+            values,
+        });
 
         let saved = prefix.len();
         if depth != 0 {
             if is_last {
-                prefix.push_str(LINE_SPACE);
+                prefix.push_str(&config.glyphs.space());
             } else {
-                prefix.push_str(LINE_VERTICAL);
-                prefix.push_str(&LINE_SPACE[..2]);
+                prefix.push_str(&config.glyphs.continuation());
             }
         }
 
-        if rows[i..j].iter().any(|r| r.0.len() > depth + 1) {
-            render_range(rows, i, j, depth + 1, prefix, out, buffer);
-        }
+        render_node(world, child, depth + 1, config, path, prefix, out);
 
         prefix.truncate(saved);
-        i = j;
+        path.truncate(saved_path);
     }
 }
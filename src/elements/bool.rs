@@ -1,7 +1,7 @@
 use bevy_ecs::world::EntityWorldMut;
-use stanza::style::{Palette16, TextFg};
 
 use super::{Content, Element, Style, Styles};
+use crate::Role;
 
 impl Element for bool {
     fn spawn(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
@@ -10,11 +10,17 @@ impl Element for bool {
             false => "FALSE ○",
         })));
 
-        entity.insert(Style(style_override.unwrap_or_else(|| {
-            Styles::new().with(TextFg(match self {
-                true => Palette16::Green,
-                false => Palette16::Red,
-            }))
-        })));
+        match style_override {
+            Some(style) => {
+                entity.insert(Style(style));
+            }
+            None => {
+                entity.remove::<Style>();
+                entity.insert(match self {
+                    true => Role::Ok,
+                    false => Role::Error,
+                });
+            }
+        }
     }
 }
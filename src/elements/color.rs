@@ -1,27 +1,64 @@
-use crate::style::{Palette16, TextFg};
+use crate::style::{BackgroundColor, Color, Palette16, TextBg, TextColor, TextFg};
 use bevy_ecs::world::EntityWorldMut;
 
-use super::{Element, Styles};
+use super::{Element, Style, Styles};
+use crate::{Children, Role};
+
+/// Element impl of a wrapper adding its colour to `style_override` with a
+/// `styles` method, the wrapped element being field `$inner`.
+macro_rules! color_wrapper {
+    ($name:ident, $inner:tt) => {
+        impl<T: Element> Element for $name<T> {
+            fn spawn(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
+                self.$inner.spawn(entity, Some(self.styles(style_override)));
+            }
+
+            fn tick(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
+                self.$inner.tick(entity, Some(self.styles(style_override)));
+            }
+
+            fn refresh(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
+                self.$inner
+                    .refresh(entity, Some(self.styles(style_override)));
+            }
+
+            fn is_mutation(&self) -> bool {
+                self.$inner.is_mutation()
+            }
+
+            fn children(&self, children: &mut Children) {
+                let style_override = children.style_override();
+                children.set_style_override(Some(self.styles(style_override)));
+                self.$inner.children(children);
+            }
+        }
+    };
+}
 
 macro_rules! color {
     ($name:ident, $palette:expr) => {
         #[derive(Debug, Clone)]
         pub struct $name<T: Element>(pub T);
 
-        impl<T: Element> Element for $name<T> {
-            fn spawn(&self, entity: &mut EntityWorldMut, _style_override: Option<Styles>) {
-                self.0
-                    .spawn(entity, Some(Styles::new().with(TextFg($palette))));
-            }
-
-            fn tick(&self, entity: &mut EntityWorldMut, _style_override: Option<Styles>) {
-                self.0
-                    .tick(entity, Some(Styles::new().with(TextFg($palette))));
+        impl<T: Element> $name<T> {
+            fn styles(&self, style_override: Option<Styles>) -> Styles {
+                foreground(style_override, TextFg($palette))
             }
         }
+
+        color_wrapper!($name, 0);
     };
 }
 
+/// `style_override` with its foreground replaced by `fg`, keeping e.g. a
+/// background set further out.
+fn foreground(style_override: Option<Styles>, fg: impl crate::style::Style) -> Styles {
+    let mut styles = style_override.unwrap_or_default();
+    styles.remove::<TextFg>();
+    styles.remove::<TextColor>();
+    styles.with(fg)
+}
+
 color!(Black, Palette16::Black);
 color!(Red, Palette16::Red);
 color!(Green, Palette16::Green);
@@ -38,3 +75,110 @@ color!(BrightBlue, Palette16::BrightBlue);
 color!(BrightMagenta, Palette16::BrightMagenta);
 color!(BrightCyan, Palette16::BrightCyan);
 color!(BrightWhite, Palette16::BrightWhite);
+
+/// 24-bit foreground colour, e.g. `Rgb(255, 128, 0, Text("warm"))`.
+/// Downgraded on terminals with fewer colours, see
+/// [`ColorSupport`](crate::ColorSupport).
+#[derive(Debug, Clone)]
+pub struct Rgb<T: Element>(pub u8, pub u8, pub u8, pub T);
+
+impl<T: Element> Rgb<T> {
+    fn styles(&self, style_override: Option<Styles>) -> Styles {
+        foreground(
+            style_override,
+            TextColor(Color::Rgb(self.0, self.1, self.2)),
+        )
+    }
+}
+
+color_wrapper!(Rgb, 3);
+
+/// Foreground from the xterm 256-colour palette, e.g. `Indexed(208, Text("amber"))`.
+#[derive(Debug, Clone)]
+pub struct Indexed<T: Element>(pub u8, pub T);
+
+impl<T: Element> Indexed<T> {
+    fn styles(&self, style_override: Option<Styles>) -> Styles {
+        foreground(style_override, TextColor(Color::Indexed(self.0)))
+    }
+}
+
+color_wrapper!(Indexed, 1);
+
+/// Background colour behind the value, combining with a foreground colour
+/// set inside or outside of it.
+///
+/// ```
+/// use nesti::{Background, Color, Nesti, Palette16, Text, White};
+///
+/// let nesti = Nesti::new();
+/// nesti.put("idle", Background(Color::Rgb(40, 40, 40), White(Text("ok"))));
+/// nesti.put("down", Background(Color::try_from(Palette16::Red).unwrap(), Text("db")));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Background<T: Element>(pub Color, pub T);
+
+impl<T: Element> Background<T> {
+    fn styles(&self, style_override: Option<Styles>) -> Styles {
+        let mut styles = style_override.unwrap_or_default();
+        styles.remove::<TextBg>();
+        styles.with(BackgroundColor(self.0))
+    }
+}
+
+color_wrapper!(Background, 1);
+
+/// Element styled through the theme, e.g. `Themed(Role::Warn, Text("degraded"))`
+/// follows the warn style of whatever theme is set when the tree is drawn.
+#[derive(Debug, Clone)]
+pub struct Themed<T: Element>(pub Role, pub T);
+
+impl<T: Element> Themed<T> {
+    /// Replace the foreground the inner element set with the role, keeping
+    /// styles such as a background from further out.
+    fn apply(&self, entity: &mut EntityWorldMut) {
+        if let Some(Style(mut styles)) = entity.take::<Style>() {
+            styles.remove::<TextFg>();
+            styles.remove::<TextColor>();
+            if !styles.is_empty() {
+                entity.insert(Style(styles));
+            }
+        }
+        entity.insert(self.0);
+    }
+}
+
+impl<T: Element> Element for Themed<T> {
+    fn spawn(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
+        self.1.spawn(entity, without_foreground(style_override));
+        self.apply(entity);
+    }
+
+    fn tick(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
+        self.1.tick(entity, without_foreground(style_override));
+        self.apply(entity);
+    }
+
+    fn refresh(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
+        self.1.refresh(entity, without_foreground(style_override));
+        self.apply(entity);
+    }
+
+    fn is_mutation(&self) -> bool {
+        self.1.is_mutation()
+    }
+
+    fn children(&self, children: &mut Children) {
+        let style_override = children.style_override();
+        children.set_style_override(without_foreground(style_override));
+        self.1.children(children);
+    }
+}
+
+/// `style_override` without a foreground, `None` if nothing else is left.
+fn without_foreground(style_override: Option<Styles>) -> Option<Styles> {
+    let mut styles = style_override?;
+    styles.remove::<TextFg>();
+    styles.remove::<TextColor>();
+    (!styles.is_empty()).then_some(styles)
+}
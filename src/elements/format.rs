@@ -0,0 +1,237 @@
+use bevy_ecs::{prelude::*, world::EntityWorldMut};
+use num_format::{Grouping, Locale, ToFormattedString};
+
+use super::{Element, NumericValue, Styles};
+use crate::Children;
+
+const SI_PREFIXES: [&str; 6] = ["k", "M", "G", "T", "P", "E"];
+
+/// How numbers are written in the value column.
+///
+/// Set one for a single element with `Integer(n).format(..)` or
+/// `Decimal(n).format(..)`, or for every number with
+/// [`Nesti::set_number_format`](crate::Nesti::set_number_format).
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq)]
+#[component(storage = "SparseSet")]
+pub struct NumberFormat {
+    /// Digits after the decimal point. Integers only use it for
+    /// [`Notation::Scientific`] and [`Notation::Compact`].
+    pub precision: usize,
+    /// Digit grouping, decimal point and minus sign.
+    pub locale: Locale,
+    /// Group the integer part of decimals too, e.g. `1,234.50` rather than
+    /// `1234.50`. Integers are always grouped.
+    pub group_decimals: bool,
+    pub notation: Notation,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Notation {
+    /// `1,234,567` and `1234567.89`, or `1,234,567.89` with
+    /// [`NumberFormat::group_decimals`]
+    #[default]
+    Standard,
+    /// `1.23e6`
+    Scientific,
+    /// Standard, switching to scientific for values that would otherwise
+    /// round to zero, e.g. `1.20e-5` rather than `0.00`.
+    Auto,
+    /// SI prefixes for values of a thousand and above, e.g. `1.2k`, `3.4M`.
+    Compact,
+}
+
+impl Default for NumberFormat {
+    fn default() -> Self {
+        Self {
+            precision: 2,
+            locale: Locale::en,
+            group_decimals: false,
+            notation: Notation::Standard,
+        }
+    }
+}
+
+impl NumberFormat {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = precision;
+        self
+    }
+
+    pub fn locale(mut self, locale: Locale) -> Self {
+        self.locale = locale;
+        self
+    }
+
+    pub fn group_decimals(mut self, group_decimals: bool) -> Self {
+        self.group_decimals = group_decimals;
+        self
+    }
+
+    pub fn notation(mut self, notation: Notation) -> Self {
+        self.notation = notation;
+        self
+    }
+
+    pub fn scientific(self) -> Self {
+        self.notation(Notation::Scientific)
+    }
+
+    pub fn auto(self) -> Self {
+        self.notation(Notation::Auto)
+    }
+
+    pub fn compact(self) -> Self {
+        self.notation(Notation::Compact)
+    }
+
+    pub fn format(&self, value: &NumericValue) -> String {
+        match self.notation {
+            Notation::Scientific => self.scientific_string(value.to_f64()),
+            Notation::Compact => self.compact_string(value),
+            Notation::Auto if !value.is_integer() && self.rounds_to_zero(value.to_f64()) => {
+                self.scientific_string(value.to_f64())
+            }
+            Notation::Standard | Notation::Auto => self.standard_string(value),
+        }
+    }
+
+    fn standard_string(&self, value: &NumericValue) -> String {
+        match *value {
+            NumericValue::I8(v) => v.to_formatted_string(&self.locale),
+            NumericValue::I16(v) => v.to_formatted_string(&self.locale),
+            NumericValue::I32(v) => v.to_formatted_string(&self.locale),
+            NumericValue::I64(v) => v.to_formatted_string(&self.locale),
+            NumericValue::I128(v) => v.to_formatted_string(&self.locale),
+            NumericValue::Isize(v) => v.to_formatted_string(&self.locale),
+            NumericValue::U8(v) => v.to_formatted_string(&self.locale),
+            NumericValue::U16(v) => v.to_formatted_string(&self.locale),
+            NumericValue::U32(v) => v.to_formatted_string(&self.locale),
+            NumericValue::U64(v) => v.to_formatted_string(&self.locale),
+            NumericValue::U128(v) => v.to_formatted_string(&self.locale),
+            NumericValue::Usize(v) => v.to_formatted_string(&self.locale),
+            NumericValue::F32(v) => self.decimal_string(v.into()),
+            NumericValue::F64(v) => self.decimal_string(v),
+        }
+    }
+
+    fn decimal_string(&self, value: f64) -> String {
+        if !value.is_finite() {
+            return value.to_string();
+        }
+
+        let fixed = format!("{:.*}", self.precision, value.abs());
+        let (int, frac) = fixed.split_once('.').unwrap_or((&fixed, ""));
+
+        let mut out = String::new();
+        if value.is_sign_negative() {
+            out.push_str(self.locale.minus_sign());
+        }
+        match self.group_decimals {
+            true => out.push_str(&group(int, &self.locale)),
+            false => out.push_str(int),
+        }
+        if !frac.is_empty() {
+            out.push_str(self.locale.decimal());
+            out.push_str(frac);
+        }
+        out
+    }
+
+    fn scientific_string(&self, value: f64) -> String {
+        format!("{:.*e}", self.precision, value)
+            .replacen('.', self.locale.decimal(), 1)
+            .replacen('-', self.locale.minus_sign(), 2)
+    }
+
+    fn compact_string(&self, value: &NumericValue) -> String {
+        let mut scaled = value.to_f64();
+        let mut prefix = None;
+
+        for si in SI_PREFIXES {
+            // Compare after rounding so 999,999 becomes 1.00M, not 1000.00k
+            let rounded = format!("{:.*}", self.precision, scaled.abs());
+            if rounded.parse::<f64>().unwrap_or(0.0) < 1000.0 {
+                break;
+            }
+            scaled /= 1000.0;
+            prefix = Some(si);
+        }
+
+        match prefix {
+            None if value.is_integer() => self.standard_string(value),
+            None => self.decimal_string(scaled),
+            Some(prefix) => format!("{}{prefix}", self.decimal_string(scaled)),
+        }
+    }
+
+    fn rounds_to_zero(&self, value: f64) -> bool {
+        value != 0.0 && value.abs() < 0.5 * 10f64.powi(-(self.precision as i32))
+    }
+}
+
+/// Separate the digits of an integer part according to `locale`.
+fn group(digits: &str, locale: &Locale) -> String {
+    let (first, rest) = match locale.grouping() {
+        Grouping::Standard => (3, 3),
+        Grouping::Indian => (3, 2),
+        Grouping::Posix => return digits.to_owned(),
+    };
+
+    let mut groups = Vec::new();
+    let mut end = digits.len();
+    let mut size = first;
+    while end > size {
+        groups.push(&digits[end - size..end]);
+        end -= size;
+        size = rest;
+    }
+    groups.push(&digits[..end]);
+    groups.reverse();
+    groups.join(locale.separator())
+}
+
+#[derive(Resource, Debug, Default)]
+pub(crate) struct DefaultNumberFormat(pub NumberFormat);
+
+/// Number format in effect for `entity`, its own or the instance default.
+pub(crate) fn number_format(entity: &EntityWorldMut) -> NumberFormat {
+    entity.get::<NumberFormat>().copied().unwrap_or_else(|| {
+        entity
+            .world()
+            .get_resource::<DefaultNumberFormat>()
+            .map(|format| format.0)
+            .unwrap_or_default()
+    })
+}
+
+/// Numeric element written with its own [`NumberFormat`].
+#[derive(Debug, Clone)]
+pub struct Formatted<E: Element>(pub E, pub NumberFormat);
+
+impl<E: Element> Element for Formatted<E> {
+    fn spawn(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
+        entity.insert(self.1);
+        self.0.spawn(entity, style_override);
+    }
+
+    fn tick(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
+        entity.insert(self.1);
+        self.0.tick(entity, style_override);
+    }
+
+    fn refresh(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
+        self.0.refresh(entity, style_override);
+    }
+
+    fn is_mutation(&self) -> bool {
+        self.0.is_mutation()
+    }
+
+    fn children(&self, children: &mut Children) {
+        self.0.children(children);
+    }
+}
@@ -0,0 +1,46 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    fmt::Display,
+    hash::BuildHasher,
+};
+
+use super::Element;
+use crate::{ChildOrder, Children};
+
+/// Entries are put as children named after their keys, and keys missing
+/// from a later put are removed. A `HashMap` has no order of its own, so new
+/// keys land after the existing ones in its arbitrary iteration order. Use a
+/// `BTreeMap` for key order or [`Entries`] for insertion order.
+impl<K: Display, V: Element, S: BuildHasher> Element for HashMap<K, V, S> {
+    fn children(&self, children: &mut Children) {
+        for (key, value) in self {
+            children.put(key.to_string(), value);
+        }
+    }
+}
+
+/// Entries are put as children named after their keys, in key order, and
+/// keys missing from a later put are removed.
+impl<K: Display, V: Element> Element for BTreeMap<K, V> {
+    fn children(&self, children: &mut Children) {
+        children.set_order(ChildOrder::Emitted);
+        for (key, value) in self {
+            children.put(key.to_string(), value);
+        }
+    }
+}
+
+/// Key-value pairs put as children in the order given, e.g.
+/// `Entries(vec![("build", "done"), ("deploy", "queued")])`. Keys missing
+/// from a later put are removed.
+#[derive(Debug, Clone)]
+pub struct Entries<K: Display, V: Element>(pub Vec<(K, V)>);
+
+impl<K: Display, V: Element> Element for Entries<K, V> {
+    fn children(&self, children: &mut Children) {
+        children.set_order(ChildOrder::Emitted);
+        for (key, value) in &self.0 {
+            children.put(key.to_string(), value);
+        }
+    }
+}
@@ -1,19 +1,33 @@
 use super::style::Styles;
-use super::{Content, Element, Style};
+use super::{Content, Element, ErrorMessage, Style};
 
 mod bool;
 mod color;
+mod format;
 mod map;
 mod number;
 mod progress;
+#[cfg(feature = "serde")]
+mod serialized;
 mod size;
 mod text;
 mod timer;
+mod tuple;
 mod vector;
 
 pub use self::color::*;
-pub use self::number::{Integer, IntegerUnit, IntegerDelta, Decimal, DecimalUnit, DecimalDelta, NumericValue};
+pub(crate) use self::format::DefaultNumberFormat;
+pub use self::format::{Formatted, Notation, NumberFormat};
+pub use self::map::Entries;
+pub use self::number::{
+    Decimal, DecimalDelta, DecimalUnit, Delta, FloatLike, Integer, IntegerDelta, IntegerLike,
+    IntegerUnit, NumericError, NumericValue, Overflow, Unit,
+};
 pub use self::progress::*;
+#[cfg(feature = "serde")]
+pub use self::serialized::Serialized;
 pub use self::size::*;
 pub use self::text::*;
 pub use self::timer::*;
+pub(crate) use self::tuple::Cells;
+pub use self::vector::Inline;
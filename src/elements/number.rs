@@ -1,12 +1,20 @@
-use std::fmt::Display;
+use std::{convert::Infallible, fmt::Display};
 
 use bevy_ecs::{component::Component, world::EntityWorldMut};
-use num_format::{Locale, ToFormattedString};
+use num_format::ToFormattedString;
 
-use super::{Content, Element, Style, Styles};
+use super::{
+    format::number_format, Content, Element, ErrorMessage, Formatted, NumberFormat, Style, Styles,
+};
 
-/// Component that stores the raw numeric value for delta operations
-#[derive(Component, Debug, Clone)]
+/// Component that stores the raw numeric value for delta operations.
+///
+/// A delta keeps the type of the value it is applied to, so `Integer(1u32)`
+/// added to a stored `u64` leaves a `u64`. The operand is converted first and
+/// must fit that type, otherwise it is handled like any other overflow. The
+/// one exception is mixing integers and decimals, which always gives a
+/// decimal of the float type involved.
+#[derive(Component, Debug, Clone, Copy, PartialEq)]
 pub enum NumericValue {
     I8(i8),
     I16(i16),
@@ -24,6 +32,131 @@ pub enum NumericValue {
     F64(f64),
 }
 
+macro_rules! each_numeric {
+    ($value:expr, $v:ident => $int:expr, $f:ident => $float:expr) => {
+        match $value {
+            NumericValue::I8($v) => $int,
+            NumericValue::I16($v) => $int,
+            NumericValue::I32($v) => $int,
+            NumericValue::I64($v) => $int,
+            NumericValue::I128($v) => $int,
+            NumericValue::Isize($v) => $int,
+            NumericValue::U8($v) => $int,
+            NumericValue::U16($v) => $int,
+            NumericValue::U32($v) => $int,
+            NumericValue::U64($v) => $int,
+            NumericValue::U128($v) => $int,
+            NumericValue::Usize($v) => $int,
+            NumericValue::F32($f) => $float,
+            NumericValue::F64($f) => $float,
+        }
+    };
+}
+
+impl NumericValue {
+    pub fn is_integer(&self) -> bool {
+        !matches!(self, NumericValue::F32(_) | NumericValue::F64(_))
+    }
+
+    pub fn is_sign_negative(&self) -> bool {
+        match *self {
+            NumericValue::I8(v) => v < 0,
+            NumericValue::I16(v) => v < 0,
+            NumericValue::I32(v) => v < 0,
+            NumericValue::I64(v) => v < 0,
+            NumericValue::I128(v) => v < 0,
+            NumericValue::Isize(v) => v < 0,
+            NumericValue::F32(v) => v.is_sign_negative(),
+            NumericValue::F64(v) => v.is_sign_negative(),
+            _ => false,
+        }
+    }
+
+    /// Nearest `f64`, large integers may lose precision.
+    pub fn to_f64(&self) -> f64 {
+        each_numeric!(*self, v => v as f64, v => v as f64)
+    }
+
+    /// Apply `delta` following the coercion rules above.
+    pub fn apply(
+        &self,
+        delta: Delta<NumericValue>,
+        overflow: Overflow,
+    ) -> Result<Self, NumericError> {
+        match (*self, *delta.value()) {
+            (value, NumericValue::F32(_)) if value.is_integer() => {
+                NumericValue::F32(value.to_f64() as f32).apply(delta, overflow)
+            }
+            (value, NumericValue::F64(_)) if value.is_integer() => {
+                NumericValue::F64(value.to_f64()).apply(delta, overflow)
+            }
+            (NumericValue::I8(v), _) => apply_integer(v, delta, overflow).map(NumericValue::I8),
+            (NumericValue::I16(v), _) => apply_integer(v, delta, overflow).map(NumericValue::I16),
+            (NumericValue::I32(v), _) => apply_integer(v, delta, overflow).map(NumericValue::I32),
+            (NumericValue::I64(v), _) => apply_integer(v, delta, overflow).map(NumericValue::I64),
+            (NumericValue::I128(v), _) => apply_integer(v, delta, overflow).map(NumericValue::I128),
+            (NumericValue::Isize(v), _) => {
+                apply_integer(v, delta, overflow).map(NumericValue::Isize)
+            }
+            (NumericValue::U8(v), _) => apply_integer(v, delta, overflow).map(NumericValue::U8),
+            (NumericValue::U16(v), _) => apply_integer(v, delta, overflow).map(NumericValue::U16),
+            (NumericValue::U32(v), _) => apply_integer(v, delta, overflow).map(NumericValue::U32),
+            (NumericValue::U64(v), _) => apply_integer(v, delta, overflow).map(NumericValue::U64),
+            (NumericValue::U128(v), _) => apply_integer(v, delta, overflow).map(NumericValue::U128),
+            (NumericValue::Usize(v), _) => {
+                apply_integer(v, delta, overflow).map(NumericValue::Usize)
+            }
+            (NumericValue::F32(v), _) => apply_float(v, delta, overflow).map(NumericValue::F32),
+            (NumericValue::F64(v), _) => apply_float(v, delta, overflow).map(NumericValue::F64),
+        }
+    }
+}
+
+fn apply_integer<T: IntegerLike>(
+    current: T,
+    delta: Delta<NumericValue>,
+    overflow: Overflow,
+) -> Result<T, NumericError> {
+    let delta = delta.try_map(|operand| match overflow {
+        Overflow::Checked => T::from_numeric_value(&operand).ok_or(NumericError::Overflow),
+        Overflow::Saturating => Ok(T::saturating_from_numeric_value(&operand)),
+        Overflow::Wrapping => Ok(T::wrapping_from_numeric_value(&operand)),
+    })?;
+
+    if let Delta::Div(d) = delta {
+        if d == T::zero() {
+            return Err(NumericError::DivisionByZero);
+        }
+    }
+
+    match overflow {
+        Overflow::Checked => current.checked(delta).ok_or(NumericError::Overflow),
+        Overflow::Saturating => Ok(current.saturating(delta)),
+        Overflow::Wrapping => Ok(current.wrapping(delta)),
+    }
+}
+
+fn apply_float<T: FloatLike>(
+    current: T,
+    delta: Delta<NumericValue>,
+    overflow: Overflow,
+) -> Result<T, NumericError> {
+    let delta = delta.map(|operand| T::from_numeric_value(&operand));
+
+    if let Delta::Div(d) = delta {
+        if d == T::zero() && overflow != Overflow::Wrapping {
+            return Err(NumericError::DivisionByZero);
+        }
+    }
+
+    let value = current.apply(delta);
+    match overflow {
+        Overflow::Checked if !value.is_finite() => Err(NumericError::Overflow),
+        Overflow::Saturating => Ok(value.saturate()),
+        _ => Ok(value),
+    }
+}
+
 /// Display integer.
 #[derive(Debug, Clone)]
 pub struct Integer<T: IntegerLike>(pub T);
@@ -32,24 +165,68 @@ impl<T: IntegerLike> Integer<T> {
     pub fn add(self) -> IntegerDelta<T> {
         IntegerDelta::Add(self.0)
     }
-    
+
     pub fn sub(self) -> IntegerDelta<T> {
         IntegerDelta::Sub(self.0)
     }
-    
+
     pub fn mul(self) -> IntegerDelta<T> {
         IntegerDelta::Mul(self.0)
     }
-    
+
     pub fn div(self) -> IntegerDelta<T> {
         IntegerDelta::Div(self.0)
     }
+
+    pub fn min(self) -> IntegerDelta<T> {
+        IntegerDelta::new(Delta::Min(self.0))
+    }
+
+    pub fn max(self) -> IntegerDelta<T> {
+        IntegerDelta::new(Delta::Max(self.0))
+    }
+
+    pub fn reset(self) -> IntegerDelta<T> {
+        IntegerDelta::new(Delta::Reset(self.0))
+    }
+
+    /// Replace the stored value with `new` if it currently equals this one.
+    pub fn compare_and_set(self, new: T) -> IntegerDelta<T> {
+        IntegerDelta::new(Delta::CompareAndSet {
+            expected: self.0,
+            new,
+        })
+    }
+
+    /// Write this number with `format` instead of the instance default.
+    pub fn format(self, format: NumberFormat) -> Formatted<Self> {
+        Formatted(self, format)
+    }
 }
 
 /// Display integer with a specified unit.
 #[derive(Debug)]
 pub struct IntegerUnit<T: IntegerLike, U: Display>(pub T, pub U);
 
+impl<T: IntegerLike, U: Display> IntegerUnit<T, U> {
+    /// Deltas keep the unit already at the path, this one is not used.
+    pub fn add(self) -> IntegerDelta<T> {
+        Integer(self.0).add()
+    }
+
+    pub fn sub(self) -> IntegerDelta<T> {
+        Integer(self.0).sub()
+    }
+
+    pub fn mul(self) -> IntegerDelta<T> {
+        Integer(self.0).mul()
+    }
+
+    pub fn div(self) -> IntegerDelta<T> {
+        Integer(self.0).div()
+    }
+}
+
 /// Display decimal.
 #[derive(Debug, Clone)]
 pub struct Decimal<T: FloatLike>(pub T);
@@ -58,462 +235,566 @@ impl<T: FloatLike> Decimal<T> {
     pub fn add(self) -> DecimalDelta<T> {
         DecimalDelta::Add(self.0)
     }
-    
+
     pub fn sub(self) -> DecimalDelta<T> {
         DecimalDelta::Sub(self.0)
     }
-    
+
     pub fn mul(self) -> DecimalDelta<T> {
         DecimalDelta::Mul(self.0)
     }
-    
+
     pub fn div(self) -> DecimalDelta<T> {
         DecimalDelta::Div(self.0)
     }
+
+    pub fn min(self) -> DecimalDelta<T> {
+        DecimalDelta::new(Delta::Min(self.0))
+    }
+
+    pub fn max(self) -> DecimalDelta<T> {
+        DecimalDelta::new(Delta::Max(self.0))
+    }
+
+    pub fn reset(self) -> DecimalDelta<T> {
+        DecimalDelta::new(Delta::Reset(self.0))
+    }
+
+    /// Replace the stored value with `new` if it currently equals this one.
+    pub fn compare_and_set(self, new: T) -> DecimalDelta<T> {
+        DecimalDelta::new(Delta::CompareAndSet {
+            expected: self.0,
+            new,
+        })
+    }
+
+    /// Write this number with `format` instead of the instance default.
+    pub fn format(self, format: NumberFormat) -> Formatted<Self> {
+        Formatted(self, format)
+    }
 }
 
 /// Display decimal with a specified unit.
 #[derive(Debug)]
 pub struct DecimalUnit<T: FloatLike, U: Display>(pub T, pub U);
 
-/// Delta operation for integer values
-#[derive(Debug)]
-pub enum IntegerDelta<T: IntegerLike> {
-    Add(T),
-    Sub(T),
-    Mul(T),
-    Div(T),
+impl<T: FloatLike, U: Display> DecimalUnit<T, U> {
+    /// Deltas keep the unit already at the path, this one is not used.
+    pub fn add(self) -> DecimalDelta<T> {
+        Decimal(self.0).add()
+    }
+
+    pub fn sub(self) -> DecimalDelta<T> {
+        Decimal(self.0).sub()
+    }
+
+    pub fn mul(self) -> DecimalDelta<T> {
+        Decimal(self.0).mul()
+    }
+
+    pub fn div(self) -> DecimalDelta<T> {
+        Decimal(self.0).div()
+    }
 }
 
-/// Delta operation for decimal values
-#[derive(Debug)]
-pub enum DecimalDelta<T: FloatLike> {
+/// Unit written after a number, kept when deltas change the number.
+#[derive(Component, Debug, Clone)]
+#[component(storage = "SparseSet")]
+pub struct Unit(pub String);
+
+/// Operation applied to the stored value by a delta. A path without a
+/// value starts from zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Delta<T> {
     Add(T),
     Sub(T),
     Mul(T),
     Div(T),
+    /// Keep the smaller of the stored value and this one.
+    Min(T),
+    /// Keep the larger of the stored value and this one, e.g. to track a
+    /// high-water mark.
+    Max(T),
+    /// Replace the stored value, keeping its type.
+    Reset(T),
+    /// Replace the stored value with `new` only if it equals `expected`.
+    CompareAndSet {
+        expected: T,
+        new: T,
+    },
 }
 
-pub trait IntegerLike: Copy + Clone {
-    type Primitive: ToFormattedString;
-
-    fn primitive(self) -> Self::Primitive;
-    fn from_numeric_value(value: &NumericValue) -> Option<Self>;
-    fn to_numeric_value(self) -> NumericValue;
-    fn add(self, other: Self) -> Self;
-    fn sub(self, other: Self) -> Self;
-    fn mul(self, other: Self) -> Self;
-    fn div(self, other: Self) -> Self;
-}
-
-impl IntegerLike for i8 {
-    type Primitive = i8;
-    fn primitive(self) -> Self::Primitive {
-        self
-    }
-    fn from_numeric_value(value: &NumericValue) -> Option<Self> {
-        match value {
-            NumericValue::I8(v) => Some(*v),
-            _ => None,
+impl<T> Delta<T> {
+    /// The operand, or `expected` for [`Delta::CompareAndSet`].
+    pub fn value(&self) -> &T {
+        match self {
+            Delta::Add(v)
+            | Delta::Sub(v)
+            | Delta::Mul(v)
+            | Delta::Div(v)
+            | Delta::Min(v)
+            | Delta::Max(v)
+            | Delta::Reset(v)
+            | Delta::CompareAndSet { expected: v, .. } => v,
         }
     }
-    fn to_numeric_value(self) -> NumericValue {
-        NumericValue::I8(self)
-    }
-    fn add(self, other: Self) -> Self { self + other }
-    fn sub(self, other: Self) -> Self { self - other }
-    fn mul(self, other: Self) -> Self { self * other }
-    fn div(self, other: Self) -> Self { self / other }
-}
-impl IntegerLike for i16 {
-    type Primitive = i16;
-    fn primitive(self) -> Self::Primitive {
-        self
-    }
-    fn from_numeric_value(value: &NumericValue) -> Option<Self> {
-        match value {
-            NumericValue::I16(v) => Some(*v),
-            _ => None,
+
+    pub fn map<U>(self, mut f: impl FnMut(T) -> U) -> Delta<U> {
+        match self.try_map(|v| Ok::<_, Infallible>(f(v))) {
+            Ok(delta) => delta,
+            Err(never) => match never {},
         }
     }
-    fn to_numeric_value(self) -> NumericValue {
-        NumericValue::I16(self)
+
+    fn try_map<U, E>(self, mut f: impl FnMut(T) -> Result<U, E>) -> Result<Delta<U>, E> {
+        Ok(match self {
+            Delta::Add(v) => Delta::Add(f(v)?),
+            Delta::Sub(v) => Delta::Sub(f(v)?),
+            Delta::Mul(v) => Delta::Mul(f(v)?),
+            Delta::Div(v) => Delta::Div(f(v)?),
+            Delta::Min(v) => Delta::Min(f(v)?),
+            Delta::Max(v) => Delta::Max(f(v)?),
+            Delta::Reset(v) => Delta::Reset(f(v)?),
+            Delta::CompareAndSet { expected, new } => Delta::CompareAndSet {
+                expected: f(expected)?,
+                new: f(new)?,
+            },
+        })
     }
-    fn add(self, other: Self) -> Self { self + other }
-    fn sub(self, other: Self) -> Self { self - other }
-    fn mul(self, other: Self) -> Self { self * other }
-    fn div(self, other: Self) -> Self { self / other }
 }
-impl IntegerLike for i32 {
-    type Primitive = i32;
-    fn primitive(self) -> Self::Primitive {
-        self
-    }
-    fn from_numeric_value(value: &NumericValue) -> Option<Self> {
-        match value {
-            NumericValue::I32(v) => Some(*v),
+
+impl<T: Copy + PartialOrd> Delta<T> {
+    /// Result of the deltas that pick a value rather than compute one, `None`
+    /// for arithmetic.
+    fn select(self, current: T) -> Option<T> {
+        match self {
+            Delta::Min(v) if v < current => Some(v),
+            Delta::Max(v) if v > current => Some(v),
+            Delta::Min(_) | Delta::Max(_) => Some(current),
+            Delta::Reset(v) => Some(v),
+            Delta::CompareAndSet { expected, new } if current == expected => Some(new),
+            Delta::CompareAndSet { .. } => Some(current),
             _ => None,
         }
     }
-    fn to_numeric_value(self) -> NumericValue {
-        NumericValue::I32(self)
-    }
-    fn add(self, other: Self) -> Self { self + other }
-    fn sub(self, other: Self) -> Self { self - other }
-    fn mul(self, other: Self) -> Self { self * other }
-    fn div(self, other: Self) -> Self { self / other }
 }
-impl IntegerLike for i64 {
-    type Primitive = i64;
-    fn primitive(self) -> Self::Primitive {
-        self
-    }
-    fn from_numeric_value(value: &NumericValue) -> Option<Self> {
-        match value {
-            NumericValue::I64(v) => Some(*v),
-            _ => None,
-        }
-    }
-    fn to_numeric_value(self) -> NumericValue {
-        NumericValue::I64(self)
-    }
-    fn add(self, other: Self) -> Self { self + other }
-    fn sub(self, other: Self) -> Self { self - other }
-    fn mul(self, other: Self) -> Self { self * other }
-    fn div(self, other: Self) -> Self { self / other }
+
+/// What a delta does when the result does not fit the value's type.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Overflow {
+    /// Keep the stored value and show an error in its place.
+    #[default]
+    Checked,
+    /// Clamp to the smallest or largest value of the type.
+    Saturating,
+    /// Wrap around at the bounds of the type. Decimals are left to follow
+    /// IEEE 754 and may become infinite.
+    Wrapping,
 }
-impl IntegerLike for i128 {
-    type Primitive = i128;
-    fn primitive(self) -> Self::Primitive {
-        self
-    }
-    fn from_numeric_value(value: &NumericValue) -> Option<Self> {
-        match value {
-            NumericValue::I128(v) => Some(*v),
-            _ => None,
-        }
-    }
-    fn to_numeric_value(self) -> NumericValue {
-        NumericValue::I128(self)
-    }
-    fn add(self, other: Self) -> Self { self + other }
-    fn sub(self, other: Self) -> Self { self - other }
-    fn mul(self, other: Self) -> Self { self * other }
-    fn div(self, other: Self) -> Self { self / other }
+
+/// Why a delta could not be applied.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NumericError {
+    Overflow,
+    DivisionByZero,
 }
-impl IntegerLike for isize {
-    type Primitive = isize;
-    fn primitive(self) -> Self::Primitive {
-        self
-    }
-    fn from_numeric_value(value: &NumericValue) -> Option<Self> {
-        match value {
-            NumericValue::Isize(v) => Some(*v),
-            _ => None,
+
+impl Display for NumericError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            NumericError::Overflow => f.write_str("overflow"),
+            NumericError::DivisionByZero => f.write_str("division by zero"),
         }
     }
-    fn to_numeric_value(self) -> NumericValue {
-        NumericValue::Isize(self)
-    }
-    fn add(self, other: Self) -> Self { self + other }
-    fn sub(self, other: Self) -> Self { self - other }
-    fn mul(self, other: Self) -> Self { self * other }
-    fn div(self, other: Self) -> Self { self / other }
 }
-impl IntegerLike for u8 {
-    type Primitive = u8;
-    fn primitive(self) -> Self::Primitive {
-        self
-    }
-    fn from_numeric_value(value: &NumericValue) -> Option<Self> {
-        match value {
-            NumericValue::U8(v) => Some(*v),
-            _ => None,
-        }
-    }
-    fn to_numeric_value(self) -> NumericValue {
-        NumericValue::U8(self)
-    }
-    fn add(self, other: Self) -> Self { self + other }
-    fn sub(self, other: Self) -> Self { self - other }
-    fn mul(self, other: Self) -> Self { self * other }
-    fn div(self, other: Self) -> Self { self / other }
+
+impl std::error::Error for NumericError {}
+
+/// Delta operation for integer values
+#[derive(Debug, Clone, Copy)]
+pub enum IntegerDelta<T: IntegerLike> {
+    Add(T),
+    Sub(T),
+    Mul(T),
+    Div(T),
+    /// Any [`Delta`], handling overflow as given.
+    Apply(Delta<T>, Overflow),
 }
-impl IntegerLike for u16 {
-    type Primitive = u16;
-    fn primitive(self) -> Self::Primitive {
-        self
+
+impl<T: IntegerLike> IntegerDelta<T> {
+    pub fn new(delta: Delta<T>) -> Self {
+        Self::Apply(delta, Overflow::default())
     }
-    fn from_numeric_value(value: &NumericValue) -> Option<Self> {
-        match value {
-            NumericValue::U16(v) => Some(*v),
-            _ => None,
+
+    /// The operation and how it handles overflow.
+    pub fn parts(self) -> (Delta<T>, Overflow) {
+        match self {
+            Self::Add(v) => (Delta::Add(v), Overflow::default()),
+            Self::Sub(v) => (Delta::Sub(v), Overflow::default()),
+            Self::Mul(v) => (Delta::Mul(v), Overflow::default()),
+            Self::Div(v) => (Delta::Div(v), Overflow::default()),
+            Self::Apply(delta, overflow) => (delta, overflow),
         }
     }
-    fn to_numeric_value(self) -> NumericValue {
-        NumericValue::U16(self)
+
+    pub fn checked(self) -> Self {
+        self.overflow(Overflow::Checked)
     }
-    fn add(self, other: Self) -> Self { self + other }
-    fn sub(self, other: Self) -> Self { self - other }
-    fn mul(self, other: Self) -> Self { self * other }
-    fn div(self, other: Self) -> Self { self / other }
-}
-impl IntegerLike for u32 {
-    type Primitive = u32;
-    fn primitive(self) -> Self::Primitive {
-        self
+
+    pub fn saturating(self) -> Self {
+        self.overflow(Overflow::Saturating)
     }
-    fn from_numeric_value(value: &NumericValue) -> Option<Self> {
-        match value {
-            NumericValue::U32(v) => Some(*v),
-            _ => None,
-        }
+
+    pub fn wrapping(self) -> Self {
+        self.overflow(Overflow::Wrapping)
     }
-    fn to_numeric_value(self) -> NumericValue {
-        NumericValue::U32(self)
+
+    pub fn overflow(self, overflow: Overflow) -> Self {
+        Self::Apply(self.parts().0, overflow)
     }
-    fn add(self, other: Self) -> Self { self + other }
-    fn sub(self, other: Self) -> Self { self - other }
-    fn mul(self, other: Self) -> Self { self * other }
-    fn div(self, other: Self) -> Self { self / other }
 }
-impl IntegerLike for u64 {
-    type Primitive = u64;
-    fn primitive(self) -> Self::Primitive {
-        self
-    }
-    fn from_numeric_value(value: &NumericValue) -> Option<Self> {
-        match value {
-            NumericValue::U64(v) => Some(*v),
-            _ => None,
-        }
-    }
-    fn to_numeric_value(self) -> NumericValue {
-        NumericValue::U64(self)
-    }
-    fn add(self, other: Self) -> Self { self + other }
-    fn sub(self, other: Self) -> Self { self - other }
-    fn mul(self, other: Self) -> Self { self * other }
-    fn div(self, other: Self) -> Self { self / other }
+
+/// Delta operation for decimal values
+#[derive(Debug, Clone, Copy)]
+pub enum DecimalDelta<T: FloatLike> {
+    Add(T),
+    Sub(T),
+    Mul(T),
+    Div(T),
+    /// Any [`Delta`], handling overflow as given.
+    Apply(Delta<T>, Overflow),
 }
-impl IntegerLike for u128 {
-    type Primitive = u128;
-    fn primitive(self) -> Self::Primitive {
-        self
+
+impl<T: FloatLike> DecimalDelta<T> {
+    pub fn new(delta: Delta<T>) -> Self {
+        Self::Apply(delta, Overflow::default())
     }
-    fn from_numeric_value(value: &NumericValue) -> Option<Self> {
-        match value {
-            NumericValue::U128(v) => Some(*v),
-            _ => None,
+
+    /// The operation and how it handles overflow.
+    pub fn parts(self) -> (Delta<T>, Overflow) {
+        match self {
+            Self::Add(v) => (Delta::Add(v), Overflow::default()),
+            Self::Sub(v) => (Delta::Sub(v), Overflow::default()),
+            Self::Mul(v) => (Delta::Mul(v), Overflow::default()),
+            Self::Div(v) => (Delta::Div(v), Overflow::default()),
+            Self::Apply(delta, overflow) => (delta, overflow),
         }
     }
-    fn to_numeric_value(self) -> NumericValue {
-        NumericValue::U128(self)
+
+    pub fn checked(self) -> Self {
+        self.overflow(Overflow::Checked)
     }
-    fn add(self, other: Self) -> Self { self + other }
-    fn sub(self, other: Self) -> Self { self - other }
-    fn mul(self, other: Self) -> Self { self * other }
-    fn div(self, other: Self) -> Self { self / other }
-}
-impl IntegerLike for usize {
-    type Primitive = usize;
-    fn primitive(self) -> Self::Primitive {
-        self
+
+    pub fn saturating(self) -> Self {
+        self.overflow(Overflow::Saturating)
     }
-    fn from_numeric_value(value: &NumericValue) -> Option<Self> {
-        match value {
-            NumericValue::Usize(v) => Some(*v),
-            _ => None,
-        }
+
+    pub fn wrapping(self) -> Self {
+        self.overflow(Overflow::Wrapping)
     }
-    fn to_numeric_value(self) -> NumericValue {
-        NumericValue::Usize(self)
+
+    pub fn overflow(self, overflow: Overflow) -> Self {
+        Self::Apply(self.parts().0, overflow)
     }
-    fn add(self, other: Self) -> Self { self + other }
-    fn sub(self, other: Self) -> Self { self - other }
-    fn mul(self, other: Self) -> Self { self * other }
-    fn div(self, other: Self) -> Self { self / other }
 }
 
+pub trait IntegerLike: Copy + Clone + PartialOrd {
+    type Primitive: ToFormattedString;
 
-pub trait FloatLike: std::fmt::Display + Copy + Clone {
+    fn primitive(self) -> Self::Primitive;
+    fn zero() -> Self;
+    /// Exact conversion, `None` for decimals and integers out of range.
     fn from_numeric_value(value: &NumericValue) -> Option<Self>;
+    /// Conversion clamped to the bounds of the type.
+    fn saturating_from_numeric_value(value: &NumericValue) -> Self;
+    /// Conversion truncating like an `as` cast.
+    fn wrapping_from_numeric_value(value: &NumericValue) -> Self;
     fn to_numeric_value(self) -> NumericValue;
-    fn add(self, other: Self) -> Self;
-    fn sub(self, other: Self) -> Self;
-    fn mul(self, other: Self) -> Self;
-    fn div(self, other: Self) -> Self;
+    fn checked(self, delta: Delta<Self>) -> Option<Self>;
+    /// Callers rule out division by zero beforehand.
+    fn saturating(self, delta: Delta<Self>) -> Self;
+    /// Callers rule out division by zero beforehand.
+    fn wrapping(self, delta: Delta<Self>) -> Self;
 }
 
-impl FloatLike for f32 {
-    fn from_numeric_value(value: &NumericValue) -> Option<Self> {
-        match value {
-            NumericValue::F32(v) => Some(*v),
-            _ => None,
-        }
-    }
-    fn to_numeric_value(self) -> NumericValue {
-        NumericValue::F32(self)
+macro_rules! integer_like {
+    ($($ty:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl IntegerLike for $ty {
+                type Primitive = $ty;
+
+                fn primitive(self) -> Self::Primitive {
+                    self
+                }
+
+                fn zero() -> Self {
+                    0
+                }
+
+                fn from_numeric_value(value: &NumericValue) -> Option<Self> {
+                    each_numeric!(*value, v => <$ty>::try_from(v).ok(), _v => None)
+                }
+
+                fn saturating_from_numeric_value(value: &NumericValue) -> Self {
+                    // Float to integer casts saturate already
+                    if !value.is_integer() {
+                        return Self::wrapping_from_numeric_value(value);
+                    }
+
+                    Self::from_numeric_value(value).unwrap_or(match value.is_sign_negative() {
+                        true => <$ty>::MIN,
+                        false => <$ty>::MAX,
+                    })
+                }
+
+                fn wrapping_from_numeric_value(value: &NumericValue) -> Self {
+                    each_numeric!(*value, v => v as $ty, v => v as $ty)
+                }
+
+                fn to_numeric_value(self) -> NumericValue {
+                    NumericValue::$variant(self)
+                }
+
+                fn checked(self, delta: Delta<Self>) -> Option<Self> {
+                    match delta {
+                        Delta::Add(d) => self.checked_add(d),
+                        Delta::Sub(d) => self.checked_sub(d),
+                        Delta::Mul(d) => self.checked_mul(d),
+                        Delta::Div(d) => self.checked_div(d),
+                        delta => delta.select(self),
+                    }
+                }
+
+                fn saturating(self, delta: Delta<Self>) -> Self {
+                    match delta {
+                        Delta::Add(d) => self.saturating_add(d),
+                        Delta::Sub(d) => self.saturating_sub(d),
+                        Delta::Mul(d) => self.saturating_mul(d),
+                        Delta::Div(d) => self.saturating_div(d),
+                        delta => delta.select(self).unwrap_or(self),
+                    }
+                }
+
+                fn wrapping(self, delta: Delta<Self>) -> Self {
+                    match delta {
+                        Delta::Add(d) => self.wrapping_add(d),
+                        Delta::Sub(d) => self.wrapping_sub(d),
+                        Delta::Mul(d) => self.wrapping_mul(d),
+                        Delta::Div(d) => self.wrapping_div(d),
+                        delta => delta.select(self).unwrap_or(self),
+                    }
+                }
+            }
+        )*
+    };
+}
+
+integer_like!(
+    i8 => I8,
+    i16 => I16,
+    i32 => I32,
+    i64 => I64,
+    i128 => I128,
+    isize => Isize,
+    u8 => U8,
+    u16 => U16,
+    u32 => U32,
+    u64 => U64,
+    u128 => U128,
+    usize => Usize,
+);
+
+pub trait FloatLike: std::fmt::Display + Copy + Clone + PartialOrd {
+    fn zero() -> Self;
+    /// Nearest value of this type, integers may lose precision.
+    fn from_numeric_value(value: &NumericValue) -> Self;
+    fn to_numeric_value(self) -> NumericValue;
+    fn apply(self, delta: Delta<Self>) -> Self;
+    fn is_finite(self) -> bool;
+    /// Clamp infinities to the largest finite value of the same sign.
+    fn saturate(self) -> Self;
+}
+
+macro_rules! float_like {
+    ($($ty:ident => $variant:ident),* $(,)?) => {
+        $(
+            impl FloatLike for $ty {
+                fn zero() -> Self {
+                    0.0
+                }
+
+                fn from_numeric_value(value: &NumericValue) -> Self {
+                    each_numeric!(*value, v => v as $ty, v => v as $ty)
+                }
+
+                fn to_numeric_value(self) -> NumericValue {
+                    NumericValue::$variant(self)
+                }
+
+                fn apply(self, delta: Delta<Self>) -> Self {
+                    match delta {
+                        Delta::Add(d) => self + d,
+                        Delta::Sub(d) => self - d,
+                        Delta::Mul(d) => self * d,
+                        Delta::Div(d) => self / d,
+                        delta => delta.select(self).unwrap_or(self),
+                    }
+                }
+
+                fn is_finite(self) -> bool {
+                    $ty::is_finite(self)
+                }
+
+                fn saturate(self) -> Self {
+                    self.clamp($ty::MIN, $ty::MAX)
+                }
+            }
+        )*
+    };
+}
+
+float_like!(f32 => F32, f64 => F64);
+
+/// Store `value` and write it out in the number format in effect, followed
+/// by the unit if there is one.
+fn set_value(entity: &mut EntityWorldMut, value: NumericValue) {
+    let mut content = number_format(entity).format(&value);
+    if let Some(unit) = entity.get::<Unit>() {
+        content.push(' ');
+        content.push_str(&unit.0);
+    }
+    entity.insert((value, Content(content)));
+}
+
+fn refresh_value(entity: &mut EntityWorldMut) {
+    if let Some(value) = entity.get::<NumericValue>().copied() {
+        set_value(entity, value);
     }
-    fn add(self, other: Self) -> Self { self + other }
-    fn sub(self, other: Self) -> Self { self - other }
-    fn mul(self, other: Self) -> Self { self * other }
-    fn div(self, other: Self) -> Self { self / other }
 }
 
-impl FloatLike for f64 {
-    fn from_numeric_value(value: &NumericValue) -> Option<Self> {
-        match value {
-            NumericValue::F64(v) => Some(*v),
-            _ => None,
+/// Apply `delta` to the stored value, or to `zero` for a path without one.
+fn apply_delta(
+    entity: &mut EntityWorldMut,
+    zero: NumericValue,
+    delta: Delta<NumericValue>,
+    overflow: Overflow,
+    style_override: Option<Styles>,
+) {
+    let current = entity.get::<NumericValue>().copied().unwrap_or(zero);
+    match current.apply(delta, overflow) {
+        Ok(value) => set_value(entity, value),
+        Err(err) => {
+            entity.insert(ErrorMessage(err.to_string()));
         }
     }
-    fn to_numeric_value(self) -> NumericValue {
-        NumericValue::F64(self)
+
+    if let Some(style) = style_override {
+        entity.insert(Style(style));
     }
-    fn add(self, other: Self) -> Self { self + other }
-    fn sub(self, other: Self) -> Self { self - other }
-    fn mul(self, other: Self) -> Self { self * other }
-    fn div(self, other: Self) -> Self { self / other }
 }
 
 impl<T: IntegerLike> Element for Integer<T> {
     fn spawn(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
-        entity.insert(Content(self.0.primitive().to_formatted_string(&Locale::en)));
-        entity.insert(NumericValue::from(self.0.to_numeric_value()));
+        set_value(entity, self.0.to_numeric_value());
         if let Some(style) = style_override {
             entity.insert(Style(style));
         }
     }
-    
-    fn tick(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
-        entity.insert(Content(self.0.primitive().to_formatted_string(&Locale::en)));
-        entity.insert(NumericValue::from(self.0.to_numeric_value()));
-        if let Some(style) = style_override {
-            entity.insert(Style(style));
-        }
+
+    // The value may have been changed by a delta since, only reformat it
+    fn refresh(&self, entity: &mut EntityWorldMut, _style_override: Option<Styles>) {
+        refresh_value(entity);
     }
 }
 
 impl<T: IntegerLike, U: Display> Element for IntegerUnit<T, U> {
     fn spawn(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
-        entity.insert(Content(format!(
-            "{} {}",
-            self.0.primitive().to_formatted_string(&Locale::en),
-            self.1
-        )));
+        entity.insert(Unit(self.1.to_string()));
+        set_value(entity, self.0.to_numeric_value());
 
         if let Some(style) = style_override {
             entity.insert(Style(style));
         }
     }
+
+    // The value may have been changed by a delta since, only reformat it
+    fn refresh(&self, entity: &mut EntityWorldMut, _style_override: Option<Styles>) {
+        refresh_value(entity);
+    }
 }
 
 impl<T: FloatLike> Element for Decimal<T> {
     fn spawn(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
-        entity.insert(Content(format!("{:.2}", self.0)));
-        entity.insert(NumericValue::from(self.0.to_numeric_value()));
+        set_value(entity, self.0.to_numeric_value());
         if let Some(style) = style_override {
             entity.insert(Style(style));
         }
     }
-    
-    fn tick(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
-        entity.insert(Content(format!("{:.2}", self.0)));
-        entity.insert(NumericValue::from(self.0.to_numeric_value()));
-        if let Some(style) = style_override {
-            entity.insert(Style(style));
-        }
+
+    // The value may have been changed by a delta since, only reformat it
+    fn refresh(&self, entity: &mut EntityWorldMut, _style_override: Option<Styles>) {
+        refresh_value(entity);
     }
 }
 
 impl<T: FloatLike, U: Display> Element for DecimalUnit<T, U> {
     fn spawn(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
-        entity.insert(Content(format!("{:.2} {}", self.0, self.1)));
+        entity.insert(Unit(self.1.to_string()));
+        set_value(entity, self.0.to_numeric_value());
+
         if let Some(style) = style_override {
             entity.insert(Style(style));
         }
     }
+
+    // The value may have been changed by a delta since, only reformat it
+    fn refresh(&self, entity: &mut EntityWorldMut, _style_override: Option<Styles>) {
+        refresh_value(entity);
+    }
 }
 
 impl<T: IntegerLike> Element for IntegerDelta<T> {
     fn spawn(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
-        // Get the existing numeric value if it exists
-        let current_value = if let Some(num_value) = entity.get::<NumericValue>() {
-            T::from_numeric_value(num_value)
-        } else {
-            None
-        };
-        
-        // Calculate the new value based on the delta operation
-        let new_value = if let Some(current) = current_value {
-            match self {
-                IntegerDelta::Add(delta) => current.add(*delta),
-                IntegerDelta::Sub(delta) => current.sub(*delta),
-                IntegerDelta::Mul(delta) => current.mul(*delta),
-                IntegerDelta::Div(delta) => current.div(*delta),
-            }
-        } else {
-            // If no existing value, treat as starting from zero and add the delta
-            match self {
-                IntegerDelta::Add(delta) => *delta,
-                IntegerDelta::Sub(_) => panic!("Cannot subtract from non-existent value"),
-                IntegerDelta::Mul(_) => panic!("Cannot multiply non-existent value"),
-                IntegerDelta::Div(_) => panic!("Cannot divide non-existent value"),
-            }
-        };
-        
-        // Store the new value
-        entity.insert(NumericValue::from(new_value.to_numeric_value()));
-        entity.insert(Content(new_value.primitive().to_formatted_string(&Locale::en)));
-        
-        if let Some(style) = style_override {
-            entity.insert(Style(style));
-        }
+        let (delta, overflow) = self.parts();
+        apply_delta(
+            entity,
+            T::zero().to_numeric_value(),
+            delta.map(T::to_numeric_value),
+            overflow,
+            style_override,
+        );
     }
-    
-    fn tick(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
-        self.spawn(entity, style_override);
+
+    // Applied once when put, elements holding on to a delta must not apply
+    // it again on every flush
+    fn refresh(&self, entity: &mut EntityWorldMut, _style_override: Option<Styles>) {
+        refresh_value(entity);
+    }
+
+    fn is_mutation(&self) -> bool {
+        true
     }
 }
 
 impl<T: FloatLike> Element for DecimalDelta<T> {
     fn spawn(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
-        // Get the existing numeric value if it exists
-        let current_value = if let Some(num_value) = entity.get::<NumericValue>() {
-            T::from_numeric_value(num_value)
-        } else {
-            None
-        };
-        
-        // Calculate the new value based on the delta operation
-        let new_value = if let Some(current) = current_value {
-            match self {
-                DecimalDelta::Add(delta) => current.add(*delta),
-                DecimalDelta::Sub(delta) => current.sub(*delta),
-                DecimalDelta::Mul(delta) => current.mul(*delta),
-                DecimalDelta::Div(delta) => current.div(*delta),
-            }
-        } else {
-            // If no existing value, treat as starting from zero and add the delta
-            match self {
-                DecimalDelta::Add(delta) => *delta,
-                DecimalDelta::Sub(_) => panic!("Cannot subtract from non-existent value"),
-                DecimalDelta::Mul(_) => panic!("Cannot multiply non-existent value"),
-                DecimalDelta::Div(_) => panic!("Cannot divide non-existent value"),
-            }
-        };
-        
-        // Store the new value
-        entity.insert(NumericValue::from(new_value.to_numeric_value()));
-        entity.insert(Content(format!("{:.2}", new_value)));
-        
-        if let Some(style) = style_override {
-            entity.insert(Style(style));
-        }
+        let (delta, overflow) = self.parts();
+        apply_delta(
+            entity,
+            T::zero().to_numeric_value(),
+            delta.map(T::to_numeric_value),
+            overflow,
+            style_override,
+        );
     }
-    
-    fn tick(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
-        self.spawn(entity, style_override);
+
+    // Applied once when put, elements holding on to a delta must not apply
+    // it again on every flush
+    fn refresh(&self, entity: &mut EntityWorldMut, _style_override: Option<Styles>) {
+        refresh_value(entity);
+    }
+
+    fn is_mutation(&self) -> bool {
+        true
     }
 }
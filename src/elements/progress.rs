@@ -1,9 +1,8 @@
-use std::time::Instant;
-
 use bevy_ecs::{component::Component, world::EntityWorldMut};
 use num_format::{Locale, ToFormattedString};
 
 use super::{Content, Element, Style, Styles, TimeComponent};
+use crate::clock;
 
 #[derive(Component, Debug)]
 pub struct ProgressState {
@@ -24,7 +23,8 @@ impl Element for Progress {
     fn spawn(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
         // Initialize tracking components for rate calculation
         if self.show_rate {
-            entity.insert_if_new(TimeComponent(Instant::now()));
+            let now = clock::now(entity.world());
+            entity.insert_if_new(TimeComponent(now));
             entity.insert_if_new(ProgressState {
                 initial_value: self.current,
                 last_value: self.current,
@@ -38,13 +38,14 @@ impl Element for Progress {
     fn tick(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
         // Update the tracking state if we're showing rate
         if self.show_rate {
+            let now = clock::now(entity.world());
             if let Some(mut state) = entity.get_mut::<ProgressState>() {
                 // Reset tracking if progress went backwards (e.g., started over)
                 if self.current < state.last_value {
                     // Reset the initial value and time when progress resets
                     state.initial_value = self.current;
                     state.last_value = self.current;
-                    entity.insert(TimeComponent(Instant::now()));
+                    entity.insert(TimeComponent(now));
                 } else {
                     state.last_value = self.current;
                 }
@@ -93,10 +94,11 @@ impl Progress {
         }
 
         if self.show_rate {
+            let now = clock::now(entity.world());
             let rate = if let (Some(time), Some(state)) =
                 (entity.get::<TimeComponent>(), entity.get::<ProgressState>())
             {
-                let elapsed = time.elapsed().as_secs_f64();
+                let elapsed = now.saturating_duration_since(time.0).as_secs_f64();
                 if elapsed > 0.0 && self.current >= state.initial_value {
                     (self.current - state.initial_value) as f64 / elapsed
                 } else {
@@ -0,0 +1,141 @@
+use bevy_ecs::{prelude::*, world::EntityWorldMut};
+use serde::Serialize;
+use serde_json::{Number, Value};
+
+use super::{Decimal, Element, ErrorMessage, Integer, Styles};
+use crate::{core::Path, ChildOrder, Children};
+
+/// Any `Serialize` value projected into the tree. Objects and structs
+/// become subtrees with a child per field, sequences become numbered
+/// children, and scalars are shown as text, numbers or booleans.
+///
+/// ```
+/// # use nesti::{Nesti, Serialized};
+/// #[derive(serde::Serialize)]
+/// struct Config {
+///     workers: u32,
+///     regions: Vec<&'static str>,
+/// }
+///
+/// let nesti = Nesti::new();
+/// nesti.put("config", Serialized(Config { workers: 4, regions: vec!["eu-west"] }));
+/// ```
+#[derive(Debug, Clone)]
+pub struct Serialized<T: Serialize>(pub T);
+
+/// Value serialized by a spawn or refresh of a [`Serialized`], handed to
+/// the `children` call that follows on the same entity so each put or
+/// flush serializes once. The call takes it, so it never outlives the data
+/// it was serialized from.
+#[derive(Component)]
+#[component(storage = "SparseSet")]
+struct SerializedValue(Value);
+
+impl<T: Serialize> Element for Serialized<T> {
+    fn spawn(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
+        match serde_json::to_value(&self.0) {
+            Ok(value) => {
+                value.spawn(entity, style_override);
+                hand_over(entity, value);
+            }
+            Err(err) => {
+                entity.insert(ErrorMessage(err.to_string()));
+            }
+        }
+    }
+
+    fn refresh(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
+        if let Ok(value) = serde_json::to_value(&self.0) {
+            value.refresh(entity, style_override);
+            hand_over(entity, value);
+        }
+    }
+
+    fn children(&self, children: &mut Children) {
+        let value = match children.entity().take::<SerializedValue>() {
+            Some(SerializedValue(value)) => value,
+            None => match serde_json::to_value(&self.0) {
+                Ok(value) => value,
+                Err(_) => return,
+            },
+        };
+        value.children(children);
+    }
+}
+
+/// Keep `value` for the children of `entity`. Entities without a path,
+/// such as the extra columns of a tuple, get no children call.
+fn hand_over(entity: &mut EntityWorldMut, value: Value) {
+    if entity.contains::<Path>() {
+        entity.insert(SerializedValue(value));
+    }
+}
+
+/// Object members are put as children in their serialized order, array
+/// items as numbered children. `null` is shown as empty text.
+impl Element for Value {
+    fn spawn(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
+        match self {
+            Value::Null => "".spawn(entity, style_override),
+            Value::Bool(value) => value.spawn(entity, style_override),
+            Value::Number(number) => spawn_number(number, entity, style_override, false),
+            Value::String(value) => value.spawn(entity, style_override),
+            Value::Array(_) | Value::Object(_) => {}
+        }
+    }
+
+    fn refresh(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
+        if let Value::Number(number) = self {
+            spawn_number(number, entity, style_override, true);
+        }
+    }
+
+    fn children(&self, children: &mut Children) {
+        match self {
+            Value::Array(items) => {
+                children.set_order(ChildOrder::Emitted);
+                for (i, item) in items.iter().enumerate() {
+                    children.put(i.to_string(), item);
+                }
+            }
+            Value::Object(map) => {
+                children.set_order(ChildOrder::Emitted);
+                for (key, value) in map {
+                    children.put(segment(key), value);
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Object key as a single path segment and row label. `/` is swapped for
+/// the look-alike `∕` so a key such as `a/b` does not reach a nested row,
+/// and an empty key is shown as `""`.
+fn segment(key: &str) -> String {
+    match key {
+        "" => "\"\"".to_owned(),
+        key => key.replace('/', "\u{2215}"),
+    }
+}
+
+fn spawn_number(
+    number: &Number,
+    entity: &mut EntityWorldMut,
+    style_override: Option<Styles>,
+    refresh: bool,
+) {
+    let element: &dyn Element = if let Some(value) = number.as_u64() {
+        &Integer(value)
+    } else if let Some(value) = number.as_i64() {
+        &Integer(value)
+    } else {
+        &Decimal(number.as_f64().unwrap_or(f64::NAN))
+    };
+
+    if refresh {
+        element.refresh(entity, style_override);
+    } else {
+        element.spawn(entity, style_override);
+    }
+}
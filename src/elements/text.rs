@@ -14,7 +14,7 @@ impl<T: Into<String> + Clone> Element for Text<T> {
     }
 }
 
-impl Element for &str {
+impl Element for str {
     fn spawn(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
         entity.insert(Content(self.to_string()));
         if let Some(style) = style_override {
@@ -3,10 +3,14 @@ use std::{
     time::{Duration, Instant},
 };
 
-use bevy_ecs::{component::Component, world::EntityWorldMut};
+use bevy_ecs::{
+    component::Component,
+    world::{EntityRef, EntityWorldMut},
+};
 use humanize_duration::{prelude::DurationExt, types::DurationParts, Formatter, Truncate, Unit};
 
 use super::{Content, Element, Style, Styles};
+use crate::clock;
 
 macro_rules! unit {
     ($unit_name:tt, $one:expr) => {
@@ -99,6 +103,25 @@ pub struct StoppedTimer {
     pub elapsed: Duration,
 }
 
+/// Timer state at a path, as returned in a [`Snapshot`](crate::Snapshot).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimerState {
+    Running(Duration),
+    Stopped(Duration),
+}
+
+impl TimerState {
+    pub(crate) fn of(entity: &EntityRef, now: Instant) -> Option<Self> {
+        if let Some(stopped) = entity.get::<StoppedTimer>() {
+            Some(Self::Stopped(stopped.elapsed))
+        } else {
+            entity
+                .get::<TimeComponent>()
+                .map(|time| Self::Running(now.saturating_duration_since(time.0)))
+        }
+    }
+}
+
 impl Deref for TimeComponent {
     type Target = Instant;
 
@@ -134,12 +157,14 @@ impl Formatter for TimerFormatter {
 
 impl Element for Timer {
     fn spawn(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
+        let now = clock::now(entity.world());
+
         // If the timer was stopped, restart it
         if entity.contains::<StoppedTimer>() {
             entity.remove::<StoppedTimer>();
-            entity.insert(TimeComponent(Instant::now()));
+            entity.insert(TimeComponent(now));
         } else {
-            entity.insert_if_new(TimeComponent(Instant::now()));
+            entity.insert_if_new(TimeComponent(now));
         }
 
         entity.insert(Content(
@@ -154,15 +179,17 @@ impl Element for Timer {
     }
 
     fn tick(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
+        let now = clock::now(entity.world());
+
         // If the timer was stopped, restart it
         if entity.contains::<StoppedTimer>() {
             entity.remove::<StoppedTimer>();
-            entity.insert(TimeComponent(Instant::now()));
+            entity.insert(TimeComponent(now));
         }
         
         // Display the current elapsed time
         if let Some(time_component) = entity.get::<TimeComponent>() {
-            let uptime = time_component.elapsed();
+            let uptime = now.saturating_duration_since(time_component.0);
             let content = uptime.human_with_format(self.0, TimerFormatter).to_string();
             entity.insert(Content(content));
         }
@@ -176,8 +203,9 @@ impl Element for Timer {
 impl Element for EndTimer {
     fn spawn(&self, entity: &mut EntityWorldMut, _style_override: Option<Styles>) {
         // If there's an active timer, stop it and freeze the elapsed time
+        let now = clock::now(entity.world());
         if let Some(time_component) = entity.get::<TimeComponent>() {
-            let elapsed = time_component.elapsed();
+            let elapsed = now.saturating_duration_since(time_component.0);
             entity.insert(StoppedTimer { elapsed });
             entity.remove::<TimeComponent>();
         }
@@ -0,0 +1,105 @@
+use bevy_ecs::{hierarchy::ChildOf, prelude::*, world::EntityWorldMut};
+
+use super::{Element, ErrorMessage, NumberFormat, Styles, Unit};
+use crate::{Children, Role};
+
+/// Entities holding the value columns of a row after the first one, which
+/// is the row's own [`Content`](crate::Content). They are despawned along
+/// with the row.
+#[derive(Component, Debug)]
+#[component(storage = "SparseSet")]
+pub(crate) struct Cells {
+    pub(crate) entities: Vec<Entity>,
+    /// Whether the element that is being put wrote these.
+    pub(crate) current: bool,
+}
+
+/// Spawn, tick or refresh `cells` into the extra columns of `entity`,
+/// reusing the column entities of an earlier put so timers and deltas keep
+/// their state.
+fn put_cells(
+    entity: &mut EntityWorldMut,
+    cells: &[&dyn Element],
+    style_override: Option<Styles>,
+    refresh: bool,
+) {
+    let row = entity.id();
+    let mut columns = entity
+        .take::<Cells>()
+        .map(|cells| cells.entities)
+        .unwrap_or_default();
+    let excess = columns.split_off(cells.len().min(columns.len()));
+
+    entity.world_scope(|world| {
+        for column in excess {
+            world.despawn(column);
+        }
+
+        for (i, cell) in cells.iter().enumerate() {
+            let Some(&column) = columns.get(i) else {
+                let mut ent = world.spawn(ChildOf(row));
+                cell.spawn(&mut ent, style_override.clone());
+                columns.push(ent.id());
+                continue;
+            };
+
+            let mut ent = world.entity_mut(column);
+            if refresh {
+                cell.refresh(&mut ent, style_override.clone());
+                continue;
+            }
+
+            // Same reset as a put at a path
+            ent.remove::<ErrorMessage>();
+            if !cell.is_mutation() {
+                ent.remove::<(NumberFormat, Unit, Role)>();
+            }
+            cell.tick(&mut ent, style_override.clone());
+        }
+    });
+
+    entity.insert(Cells {
+        entities: columns,
+        current: true,
+    });
+}
+
+/// Tuples fill a column each, e.g. `(Progress { .. }, Text("3 MB/s"))`
+/// shows the progress bar in the first value column and the rate in the
+/// second. See [`Nesti::set_columns`](crate::Nesti::set_columns) for
+/// headers and widths.
+macro_rules! tuple {
+    ($first:ident $(, $name:ident $index:tt)+) => {
+        impl<$first: Element, $($name: Element),+> Element for ($first, $($name),+) {
+            fn spawn(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
+                self.0.spawn(entity, style_override.clone());
+                put_cells(entity, &[$(&self.$index),+], style_override, false);
+            }
+
+            fn tick(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
+                self.0.tick(entity, style_override.clone());
+                put_cells(entity, &[$(&self.$index),+], style_override, false);
+            }
+
+            fn refresh(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
+                self.0.refresh(entity, style_override.clone());
+                put_cells(entity, &[$(&self.$index),+], style_override, true);
+            }
+
+            fn is_mutation(&self) -> bool {
+                self.0.is_mutation() $(&& self.$index.is_mutation())+
+            }
+
+            fn children(&self, children: &mut Children) {
+                self.0.children(children);
+                $(self.$index.children(children);)+
+            }
+        }
+    };
+}
+
+tuple!(A, B 1);
+tuple!(A, B 1, C 2);
+tuple!(A, B 1, C 2, D 3);
+tuple!(A, B 1, C 2, D 3, E 4);
+tuple!(A, B 1, C 2, D 3, E 4, F 5);
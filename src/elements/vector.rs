@@ -0,0 +1,55 @@
+use bevy_ecs::world::EntityWorldMut;
+
+use super::{Content, Element, Style, Styles};
+use crate::{ChildOrder, Children};
+
+/// Items are put as numbered children, `path/0`, `path/1` and so on. When
+/// the list shrinks the children past its end are removed.
+impl<T: Element> Element for [T] {
+    fn children(&self, children: &mut Children) {
+        children.set_order(ChildOrder::Emitted);
+        for (i, item) in self.iter().enumerate() {
+            children.put(i.to_string(), item);
+        }
+    }
+}
+
+impl<T: Element> Element for Vec<T> {
+    fn children(&self, children: &mut Children) {
+        self.as_slice().children(children);
+    }
+}
+
+impl<T: Element, const N: usize> Element for [T; N] {
+    fn children(&self, children: &mut Children) {
+        self.as_slice().children(children);
+    }
+}
+
+/// Short items joined on the row of the path itself, e.g.
+/// `Inline(vec!["eu-west", "us-east"])` shows `eu-west, us-east`.
+#[derive(Debug, Clone)]
+pub struct Inline<T: Element>(pub Vec<T>);
+
+impl<T: Element> Element for Inline<T> {
+    fn spawn(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
+        // Render each item on a scratch entity to get at its text
+        let items = entity.world_scope(|world| {
+            self.0
+                .iter()
+                .filter_map(|item| {
+                    let mut scratch = world.spawn_empty();
+                    item.spawn(&mut scratch, None);
+                    let content = scratch.take::<Content>();
+                    scratch.despawn();
+                    content.map(|c| c.0)
+                })
+                .collect::<Vec<_>>()
+        });
+
+        entity.insert(Content(items.join(", ")));
+        if let Some(style) = style_override {
+            entity.insert(Style(style));
+        }
+    }
+}
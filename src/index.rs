@@ -0,0 +1,209 @@
+use std::collections::HashMap;
+
+use bevy_ecs::prelude::*;
+
+use crate::pattern::PathPattern;
+
+/// Trie of path segments kept as a resource alongside the entities, so that
+/// lookups cost the depth of a path rather than the number of nodes.
+#[derive(Resource, Debug, Default)]
+pub(crate) struct PathIndex {
+    root: Node,
+    counter: usize,
+}
+
+#[derive(Debug, Default)]
+pub(crate) struct Node {
+    pub(crate) entity: Option<Entity>,
+    order: usize,
+    children: HashMap<String, Node>,
+}
+
+/// Split a path into its non-empty segments, so `a//b/` and `a/b` are equal.
+pub(crate) fn segments(path: &str) -> impl Iterator<Item = &str> {
+    path.split('/').filter(|s| !s.is_empty())
+}
+
+pub(crate) fn normalize(path: &str) -> String {
+    segments(path).collect::<Vec<_>>().join("/")
+}
+
+impl PathIndex {
+    pub(crate) fn get(&self, path: &str) -> Option<Entity> {
+        self.node(path).and_then(|node| node.entity)
+    }
+
+    pub(crate) fn root(&self) -> &Node {
+        &self.root
+    }
+
+    pub(crate) fn node(&self, path: &str) -> Option<&Node> {
+        segments(path).try_fold(&self.root, |node, seg| node.children.get(seg))
+    }
+
+    /// Register `entity` at `path`, creating any intermediate nodes. Nodes are
+    /// ordered by when they were first created.
+    pub(crate) fn insert(&mut self, path: &str, entity: Entity) {
+        let mut node = &mut self.root;
+        for seg in segments(path) {
+            node = node.children.entry(seg.to_owned()).or_insert_with(|| {
+                self.counter += 1;
+                Node {
+                    order: self.counter,
+                    ..Node::default()
+                }
+            });
+        }
+        node.entity = Some(entity);
+    }
+
+    /// Order the children of `path` named in `listed` as listed, keeping the
+    /// positions of any other children.
+    pub(crate) fn reorder(&mut self, path: &str, listed: &[String]) {
+        let node = segments(path).try_fold(&mut self.root, |node, seg| node.children.get_mut(seg));
+        let Some(node) = node else {
+            return;
+        };
+
+        let listed = listed
+            .iter()
+            .filter(|seg| node.children.contains_key(*seg))
+            .collect::<Vec<_>>();
+        let mut orders = listed
+            .iter()
+            .map(|seg| node.children[*seg].order)
+            .collect::<Vec<_>>();
+        orders.sort_unstable();
+
+        for (seg, order) in listed.into_iter().zip(orders) {
+            if let Some(child) = node.children.get_mut(seg) {
+                child.order = order;
+            }
+        }
+    }
+
+    /// Remove the node at `path` along with its whole subtree.
+    pub(crate) fn remove(&mut self, path: &str) -> Vec<Entity> {
+        self.remove_at(path, Removal::Subtree)
+    }
+
+    /// Remove only the entity at `path`, keeping its descendants.
+    pub(crate) fn remove_exact(&mut self, path: &str) -> Vec<Entity> {
+        self.remove_at(path, Removal::Node)
+    }
+
+    /// Remove everything below `path`, keeping the node itself.
+    pub(crate) fn remove_children(&mut self, path: &str) -> Vec<Entity> {
+        self.remove_at(path, Removal::Children)
+    }
+
+    /// Paths holding an entity that match `pattern`, in tree order.
+    pub(crate) fn matching(&self, pattern: &PathPattern) -> Vec<String> {
+        let mut matched = Vec::new();
+        self.root.matching(&mut Vec::new(), pattern, &mut matched);
+        matched
+    }
+
+    /// Remove every node matching `pattern` along with its subtree.
+    pub(crate) fn remove_matching(&mut self, pattern: &PathPattern) -> Vec<Entity> {
+        let mut removed = Vec::new();
+        self.root
+            .remove_matching(&mut Vec::new(), pattern, &mut removed);
+        removed
+    }
+
+    fn remove_at(&mut self, path: &str, removal: Removal) -> Vec<Entity> {
+        let segments = segments(path).collect::<Vec<_>>();
+        let mut removed = Vec::new();
+        self.root.remove_at(&segments, removal, &mut removed);
+        removed
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Removal {
+    Node,
+    Children,
+    Subtree,
+}
+
+impl Node {
+    /// Child nodes in the order they were first inserted.
+    pub(crate) fn children(&self) -> Vec<(&str, &Node)> {
+        let mut children = self
+            .children
+            .iter()
+            .map(|(seg, node)| (seg.as_str(), node))
+            .collect::<Vec<_>>();
+        children.sort_unstable_by_key(|(_, node)| node.order);
+        children
+    }
+
+    fn is_empty(&self) -> bool {
+        self.entity.is_none() && self.children.is_empty()
+    }
+
+    fn remove_at(&mut self, segments: &[&str], removal: Removal, removed: &mut Vec<Entity>) {
+        let Some((seg, rest)) = segments.split_first() else {
+            if !matches!(removal, Removal::Children) {
+                removed.extend(self.entity.take());
+            }
+            if !matches!(removal, Removal::Node) {
+                self.drain(removed);
+            }
+            return;
+        };
+
+        if let Some(child) = self.children.get_mut(*seg) {
+            child.remove_at(rest, removal, removed);
+            if child.is_empty() {
+                self.children.remove(*seg);
+            }
+        }
+    }
+
+    fn drain(&mut self, removed: &mut Vec<Entity>) {
+        for (_, mut child) in self.children.drain() {
+            removed.extend(child.entity.take());
+            child.drain(removed);
+        }
+    }
+
+    fn matching<'a>(
+        &'a self,
+        path: &mut Vec<&'a str>,
+        pattern: &PathPattern,
+        matched: &mut Vec<String>,
+    ) {
+        for (seg, child) in self.children() {
+            path.push(seg);
+            if child.entity.is_some() && pattern.matches_segments(path) {
+                matched.push(path.join("/"));
+            }
+            child.matching(path, pattern, matched);
+            path.pop();
+        }
+    }
+
+    fn remove_matching(
+        &mut self,
+        path: &mut Vec<String>,
+        pattern: &PathPattern,
+        removed: &mut Vec<Entity>,
+    ) {
+        for (seg, child) in self.children.iter_mut() {
+            path.push(seg.clone());
+            let segments = path.iter().map(String::as_str).collect::<Vec<_>>();
+            if pattern.matches_segments(&segments) {
+                removed.extend(child.entity.take());
+                child.drain(removed);
+            } else {
+                child.remove_matching(path, pattern, removed);
+            }
+            path.pop();
+        }
+
+        // Drop branches that no longer lead to an entity
+        self.children.retain(|_, child| !child.is_empty());
+    }
+}
@@ -0,0 +1,132 @@
+use stanza::renderer::console::Decor;
+use unicode_width::UnicodeWidthStr;
+
+/// Characters used to draw the tree connectors. Connectors are drawn as
+/// given, the space below a connector is as wide as `corner`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Glyphs {
+    pub vertical: &'static str,
+    pub corner: &'static str,
+    pub junction: &'static str,
+    /// Marks truncated labels and values and the rows scrolled out of view.
+    pub ellipsis: &'static str,
+}
+
+impl Glyphs {
+    /// `├─` and `╰─`, the default.
+    pub const ROUNDED: Self = Self {
+        vertical: "│",
+        corner: "╰─ ",
+        junction: "├─ ",
+        ellipsis: "…",
+    };
+
+    /// `├─` and `└─`.
+    pub const SQUARE: Self = Self {
+        vertical: "│",
+        corner: "└─ ",
+        junction: "├─ ",
+        ellipsis: "…",
+    };
+
+    /// `┣━` and `┗━`.
+    pub const HEAVY: Self = Self {
+        vertical: "┃",
+        corner: "┗━ ",
+        junction: "┣━ ",
+        ellipsis: "…",
+    };
+
+    /// `|-` and `` `- ``, for terminals without box-drawing characters.
+    pub const ASCII: Self = Self {
+        vertical: "|",
+        corner: "`- ",
+        junction: "|- ",
+        ellipsis: "...",
+    };
+
+    /// Prefix drawn below a junction, continuing the vertical line.
+    pub(crate) fn continuation(&self) -> String {
+        let pad = self.corner.width().saturating_sub(self.vertical.width());
+        format!("{}{}", self.vertical, " ".repeat(pad))
+    }
+
+    /// Prefix drawn below a corner.
+    pub(crate) fn space(&self) -> String {
+        " ".repeat(self.corner.width())
+    }
+}
+
+impl Default for Glyphs {
+    fn default() -> Self {
+        Self::ROUNDED
+    }
+}
+
+/// Layout of the rendered tree, see
+/// [`Nesti::set_render_config`](crate::Nesti::set_render_config).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RenderConfig {
+    pub glyphs: Glyphs,
+    /// Blanks in front of every row.
+    pub indent: usize,
+    /// Labels are padded to at least this width so values line up.
+    pub label_min_width: usize,
+    /// Blank lines above the tree.
+    pub padding_top: usize,
+    /// Blank lines below the tree.
+    pub padding_bottom: usize,
+    /// Borders and separators drawn by stanza around and between columns.
+    /// Width fitting assumes a single blank between columns.
+    pub decor: Decor,
+}
+
+impl Default for RenderConfig {
+    fn default() -> Self {
+        Self {
+            glyphs: Glyphs::default(),
+            indent: 3,
+            label_min_width: 30,
+            padding_top: 1,
+            padding_bottom: 0,
+            decor: Decor {
+                up_thin_down_thin: ' ',
+                draw_outer_border: false,
+                draw_inner_horizontal_border: false,
+                ..Decor::default()
+            },
+        }
+    }
+}
+
+impl RenderConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn glyphs(mut self, glyphs: Glyphs) -> Self {
+        self.glyphs = glyphs;
+        self
+    }
+
+    pub fn indent(mut self, indent: usize) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    pub fn label_min_width(mut self, width: usize) -> Self {
+        self.label_min_width = width;
+        self
+    }
+
+    pub fn padding(mut self, top: usize, bottom: usize) -> Self {
+        self.padding_top = top;
+        self.padding_bottom = bottom;
+        self
+    }
+
+    pub fn decor(mut self, decor: Decor) -> Self {
+        self.decor = decor;
+        self
+    }
+}
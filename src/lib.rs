@@ -1,14 +1,35 @@
-use std::io::Error;
+use std::io::{Error, Write};
 use std::{sync::LazyLock, thread::sleep, time::Duration};
 
 pub mod style;
+pub mod testing;
 
+mod ansi;
+mod children;
+mod clock;
 mod core;
 mod elements;
+mod index;
+mod layout;
+mod pattern;
+mod theme;
 
+pub use crate::ansi::ColorSupport;
+pub use crate::children::{ChildOrder, Children};
+pub use crate::clock::{Clock, ManualClock, SystemClock};
 pub use crate::core::*;
 pub use crate::elements::*;
+pub use crate::layout::{Glyphs, RenderConfig};
+pub use crate::pattern::PathPattern;
+pub use crate::style::{Color, Palette16};
+pub use crate::theme::{Role, Theme};
+pub use bevy_ecs::world::EntityWorldMut;
 pub use humanize_duration::Truncate::*;
+pub use num_format::Locale;
+pub use stanza::renderer::console::Decor;
+
+#[cfg(feature = "derive")]
+pub use nesti_derive::NestiElement;
 
 static GLOBAL_NESTI: LazyLock<Nesti> = LazyLock::new(Nesti::default);
 
@@ -24,6 +45,30 @@ pub fn nesti_pop(path: &str) {
     GLOBAL_NESTI.pop(path);
 }
 
+pub fn nesti_pop_exact(path: &str) {
+    GLOBAL_NESTI.pop_exact(path);
+}
+
+pub fn nesti_pop_children(path: &str) {
+    GLOBAL_NESTI.pop_children(path);
+}
+
+/// Redirect the global instance, e.g. `nesti_writer(std::io::stderr())` when
+/// stdout carries piped data.
+pub fn nesti_writer<W>(writer: W)
+where
+    W: Write + Send + 'static,
+{
+    GLOBAL_NESTI.set_writer(writer);
+}
+
+pub fn nesti_pop_matching<P>(pattern: P)
+where
+    P: Into<PathPattern>,
+{
+    GLOBAL_NESTI.pop_matching(pattern);
+}
+
 pub fn nesti_flush() -> Result<(), Error> {
     GLOBAL_NESTI.flush()
 }
@@ -0,0 +1,132 @@
+use crate::index::segments;
+
+/// Glob-style pattern matched against whole path segments.
+///
+/// `*` and `?` match any run of characters or a single character within one
+/// segment, while a `**` segment matches any number of segments, including
+/// none. So `workers/*/status` matches `workers/3/status` and `jobs/**/tmp`
+/// matches both `jobs/tmp` and `jobs/a/b/tmp`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PathPattern {
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    Any,
+    Glob(Vec<char>),
+}
+
+impl PathPattern {
+    pub fn new(pattern: &str) -> Self {
+        Self {
+            segments: segments(pattern)
+                .map(|seg| match seg {
+                    "**" => Segment::Any,
+                    seg => Segment::Glob(seg.chars().collect()),
+                })
+                .collect(),
+        }
+    }
+
+    pub fn matches(&self, path: &str) -> bool {
+        self.matches_segments(&segments(path).collect::<Vec<_>>())
+    }
+
+    pub(crate) fn matches_segments(&self, path: &[&str]) -> bool {
+        match_segments(&self.segments, path)
+    }
+}
+
+impl From<&str> for PathPattern {
+    fn from(pattern: &str) -> Self {
+        Self::new(pattern)
+    }
+}
+
+impl From<String> for PathPattern {
+    fn from(pattern: String) -> Self {
+        Self::new(&pattern)
+    }
+}
+
+impl From<&String> for PathPattern {
+    fn from(pattern: &String) -> Self {
+        Self::new(pattern)
+    }
+}
+
+fn match_segments(pattern: &[Segment], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((Segment::Any, rest)) => {
+            match_segments(rest, path) || (!path.is_empty() && match_segments(pattern, &path[1..]))
+        }
+        Some((Segment::Glob(glob), rest)) => {
+            !path.is_empty()
+                && match_glob(glob, &path[0].chars().collect::<Vec<_>>())
+                && match_segments(rest, &path[1..])
+        }
+    }
+}
+
+fn match_glob(glob: &[char], text: &[char]) -> bool {
+    match glob.split_first() {
+        None => text.is_empty(),
+        Some(('*', rest)) => (0..=text.len()).any(|skip| match_glob(rest, &text[skip..])),
+        Some(('?', rest)) => !text.is_empty() && match_glob(rest, &text[1..]),
+        Some((c, rest)) => text.first() == Some(c) && match_glob(rest, &text[1..]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PathPattern;
+
+    fn matches(pattern: &str, path: &str) -> bool {
+        PathPattern::new(pattern).matches(path)
+    }
+
+    #[test]
+    fn star_matches_within_one_segment() {
+        assert!(matches("workers/*/status", "workers/3/status"));
+        assert!(matches("workers/w*", "workers/w"));
+        assert!(matches("workers/*-old", "workers/a-b-old"));
+        assert!(!matches("workers/*", "workers/3/status"));
+        assert!(!matches("workers/*/status", "workers/status"));
+    }
+
+    #[test]
+    fn question_mark_matches_one_char() {
+        assert!(matches("job?", "job1"));
+        assert!(!matches("job?", "job"));
+        assert!(!matches("job?", "job12"));
+        assert!(matches("j?b/?", "jéb/x"));
+    }
+
+    #[test]
+    fn double_star_matches_any_number_of_segments() {
+        assert!(matches("jobs/**/tmp", "jobs/tmp"));
+        assert!(matches("jobs/**/tmp", "jobs/a/b/tmp"));
+        assert!(matches("**", "a/b/c"));
+        assert!(matches("**/tmp", "tmp"));
+        assert!(!matches("jobs/**/tmp", "jobs/a/tmp/b"));
+        assert!(!matches("jobs/**", "other/a"));
+    }
+
+    #[test]
+    fn patterns_are_anchored_at_both_ends() {
+        assert!(matches("jobs/build", "jobs/build"));
+        assert!(!matches("build", "jobs/build"));
+        assert!(!matches("jobs", "jobs/build"));
+        assert!(!matches("jobs/build", "jobs/build2"));
+    }
+
+    #[test]
+    fn empty_segments_are_ignored() {
+        assert!(matches("/jobs//*/", "jobs/build"));
+        assert!(matches("jobs/*", "/jobs//build/"));
+        assert!(matches("", ""));
+        assert!(!matches("", "jobs"));
+    }
+}
@@ -1,4 +1,6 @@
-use stanza::style::Styles as StanzaStyles;
+use std::fmt;
+
+use stanza::style::{Assignability, Styles as StanzaStyles};
 
 pub use stanza::style::blink::*;
 pub use stanza::style::bold::*;
@@ -20,9 +22,77 @@ pub use stanza::style::text_invert::*;
 pub use stanza::style::underline::*;
 pub use stanza::style::Style;
 
+/// Colour beyond the 16 of [`Palette16`], drawn with [`TextColor`] or
+/// [`BackgroundColor`] and downgraded to what the terminal can show, see
+/// [`ColorSupport`](crate::ColorSupport).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Color {
+    /// Entry of the xterm 256-colour palette, the first 16 being the
+    /// [`Palette16`] colours.
+    Indexed(u8),
+    Rgb(u8, u8, u8),
+}
+
+/// Fails for [`Palette16::Default`] and [`Palette16::Hidden`], which are no
+/// colour of their own, handing the input back.
+impl TryFrom<Palette16> for Color {
+    type Error = Palette16;
+
+    fn try_from(color: Palette16) -> Result<Self, Self::Error> {
+        let index = match color {
+            Palette16::Black => 0,
+            Palette16::Red => 1,
+            Palette16::Green => 2,
+            Palette16::Yellow => 3,
+            Palette16::Blue => 4,
+            Palette16::Magenta => 5,
+            Palette16::Cyan => 6,
+            Palette16::White => 7,
+            Palette16::BrightBlack => 8,
+            Palette16::BrightRed => 9,
+            Palette16::BrightGreen => 10,
+            Palette16::BrightYellow => 11,
+            Palette16::BrightBlue => 12,
+            Palette16::BrightMagenta => 13,
+            Palette16::BrightCyan => 14,
+            Palette16::BrightWhite => 15,
+            Palette16::Default | Palette16::Hidden => return Err(color),
+        };
+        Ok(Self::Indexed(index))
+    }
+}
+
+/// Foreground [`Color`], taking precedence over [`TextFg`].
+#[derive(Debug, Clone)]
+pub struct TextColor(pub Color);
+
+impl Style for TextColor {
+    fn assignability(&self) -> Assignability {
+        Assignability::CellRowColTable
+    }
+}
+
+/// Background [`Color`], taking precedence over [`TextBg`].
+#[derive(Debug, Clone)]
+pub struct BackgroundColor(pub Color);
+
+impl Style for BackgroundColor {
+    fn assignability(&self) -> Assignability {
+        Assignability::CellRowColTable
+    }
+}
+
 #[derive(Clone)]
 pub struct Styles(pub(crate) StanzaStyles);
 
+impl fmt::Debug for Styles {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set()
+            .entries((&self.0).into_iter().map(|(id, _)| id))
+            .finish()
+    }
+}
+
 impl Default for Styles {
     fn default() -> Self {
         Self::new()
@@ -38,6 +108,19 @@ impl Styles {
         self.0 = self.0.with(style);
         self
     }
+
+    /// Look up a single style, e.g. `styles.get::<TextFg>()`.
+    pub fn get<S: Style>(&self) -> Option<&S> {
+        S::resolve(&self.0)
+    }
+
+    pub(crate) fn remove<S: Style>(&mut self) {
+        self.0.take(&S::id());
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        (&self.0).into_iter().next().is_none()
+    }
 }
 
 unsafe impl Send for Styles {}
@@ -0,0 +1,112 @@
+//! Helpers for snapshot testing status trees.
+//!
+//! ```
+//! use nesti::{testing::assert_tree, Integer, Nesti};
+//!
+//! let nesti = Nesti::new();
+//! nesti.put("jobs/queued", Integer(3));
+//! nesti.put("jobs/running", Integer(1));
+//!
+//! assert_tree(
+//!     &nesti,
+//!     "
+//!     jobs
+//!     |- queued                   3
+//!     `- running                  1
+//!     ",
+//! );
+//! ```
+
+use std::{io, sync::Arc};
+
+use parking_lot::Mutex;
+
+use crate::{Nesti, RenderOptions};
+
+/// In-memory output target that can be read back after it was handed to
+/// [`Nesti::with_writer`], as clones share the same bytes.
+///
+/// ```
+/// use nesti::{testing::SharedBuffer, Integer, Nesti};
+///
+/// let buffer = SharedBuffer::new();
+/// let nesti = Nesti::with_writer(buffer.clone());
+/// nesti.put("jobs", Integer(3));
+/// nesti.flush().unwrap();
+///
+/// assert!(buffer.contents().contains("jobs"));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+impl SharedBuffer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Everything written so far, lossily decoded as UTF-8.
+    pub fn contents(&self) -> String {
+        String::from_utf8_lossy(&self.0.lock()).into_owned()
+    }
+
+    /// Drop everything written so far.
+    pub fn clear(&self) {
+        self.0.lock().clear();
+    }
+}
+
+impl io::Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.0.lock().extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// Render `nesti` as plain ASCII with the surrounding blank lines, trailing
+/// whitespace and common indentation removed.
+pub fn render(nesti: &Nesti) -> String {
+    normalize(&nesti.render_to_string(RenderOptions::plain()))
+}
+
+/// Assert that `nesti` renders to `expected`. Both sides are normalized as in
+/// [`render`], so `expected` can be an indented multi-line literal.
+#[track_caller]
+pub fn assert_tree(nesti: &Nesti, expected: &str) {
+    let actual = render(nesti);
+    let expected = normalize(expected);
+
+    assert!(
+        actual == expected,
+        "tree does not match\n\n--- expected\n{expected}\n\n--- actual\n{actual}\n"
+    );
+}
+
+/// Trim trailing whitespace and blank leading and trailing lines, then strip
+/// the indentation shared by every remaining line.
+pub fn normalize(tree: &str) -> String {
+    let lines = tree.lines().map(str::trim_end).collect::<Vec<_>>();
+
+    let start = lines.iter().position(|line| !line.is_empty());
+    let end = lines.iter().rposition(|line| !line.is_empty());
+    let (Some(start), Some(end)) = (start, end) else {
+        return String::new();
+    };
+    let lines = &lines[start..=end];
+
+    let indent = lines
+        .iter()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+
+    lines
+        .iter()
+        .map(|line| line.get(indent..).unwrap_or_default())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
@@ -0,0 +1,123 @@
+use bevy_ecs::prelude::*;
+
+use crate::style::{Bold, Palette16, Styles, TextFg};
+
+/// What a piece of the tree stands for, resolved to a style through the
+/// [`Theme`] when the tree is drawn. Elements insert it on their entity in
+/// place of a fixed [`Style`](crate::Style), so swapping the theme restyles
+/// rows that are already in the tree.
+#[derive(Component, Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[component(storage = "SparseSet")]
+pub enum Role {
+    /// Tree connectors in front of the labels.
+    Connector,
+    Label,
+    /// Values without a more specific role.
+    Value,
+    Ok,
+    Warn,
+    Error,
+    Muted,
+    Accent,
+}
+
+/// Styles for each [`Role`], set with
+/// [`Nesti::set_theme`](crate::Nesti::set_theme).
+#[derive(Debug, Clone)]
+pub struct Theme {
+    pub connector: Styles,
+    pub label: Styles,
+    pub value: Styles,
+    pub ok: Styles,
+    pub warn: Styles,
+    pub error: Styles,
+    pub muted: Styles,
+    pub accent: Styles,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl Theme {
+    /// For dark terminal backgrounds, the default.
+    pub fn dark() -> Self {
+        Self {
+            connector: fg(Palette16::BrightBlack),
+            label: Styles::new(),
+            value: Styles::new(),
+            ok: fg(Palette16::Green),
+            warn: fg(Palette16::Yellow),
+            error: fg(Palette16::Red),
+            muted: fg(Palette16::BrightBlack),
+            accent: fg(Palette16::Cyan),
+        }
+    }
+
+    /// For light terminal backgrounds, avoiding yellow and bright colours
+    /// that wash out on white.
+    pub fn light() -> Self {
+        Self {
+            connector: fg(Palette16::BrightBlack),
+            label: Styles::new(),
+            value: Styles::new(),
+            ok: fg(Palette16::Green),
+            warn: fg(Palette16::Magenta),
+            error: fg(Palette16::Red),
+            muted: fg(Palette16::BrightBlack),
+            accent: fg(Palette16::Blue),
+        }
+    }
+
+    /// Bright, bold colours and no dimmed text.
+    pub fn high_contrast() -> Self {
+        Self {
+            connector: fg(Palette16::BrightWhite),
+            label: Styles::new().with(Bold(true)),
+            value: fg(Palette16::BrightWhite),
+            ok: fg(Palette16::BrightGreen).with(Bold(true)),
+            warn: fg(Palette16::BrightYellow).with(Bold(true)),
+            error: fg(Palette16::BrightRed).with(Bold(true)),
+            muted: fg(Palette16::White),
+            accent: fg(Palette16::BrightCyan).with(Bold(true)),
+        }
+    }
+
+    /// Replace the style of a single role.
+    pub fn with(mut self, role: Role, styles: Styles) -> Self {
+        *self.style_mut(role) = styles;
+        self
+    }
+
+    pub fn style(&self, role: Role) -> &Styles {
+        match role {
+            Role::Connector => &self.connector,
+            Role::Label => &self.label,
+            Role::Value => &self.value,
+            Role::Ok => &self.ok,
+            Role::Warn => &self.warn,
+            Role::Error => &self.error,
+            Role::Muted => &self.muted,
+            Role::Accent => &self.accent,
+        }
+    }
+
+    fn style_mut(&mut self, role: Role) -> &mut Styles {
+        match role {
+            Role::Connector => &mut self.connector,
+            Role::Label => &mut self.label,
+            Role::Value => &mut self.value,
+            Role::Ok => &mut self.ok,
+            Role::Warn => &mut self.warn,
+            Role::Error => &mut self.error,
+            Role::Muted => &mut self.muted,
+            Role::Accent => &mut self.accent,
+        }
+    }
+}
+
+fn fg(color: Palette16) -> Styles {
+    Styles::new().with(TextFg(color))
+}
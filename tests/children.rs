@@ -0,0 +1,102 @@
+use std::time::Duration;
+
+use nesti::{
+    style::Styles, testing::assert_tree, Children, Element, EntityWorldMut, Integer, ManualClock,
+    Nesti, Progress, Second, Timer,
+};
+
+/// Progress row with a count per shard below it.
+struct Sharded {
+    done: u64,
+    shards: Vec<u64>,
+}
+
+impl Element for Sharded {
+    fn spawn(&self, entity: &mut EntityWorldMut, style_override: Option<Styles>) {
+        Progress {
+            current: self.done,
+            maximum: 100,
+            show_percent: true,
+            show_values: false,
+            show_rate: false,
+        }
+        .spawn(entity, style_override);
+    }
+
+    fn children(&self, children: &mut Children) {
+        for (i, count) in self.shards.iter().enumerate() {
+            children.put(format!("shard-{i}"), &Integer(*count));
+        }
+    }
+}
+
+#[test]
+fn element_puts_children_below_its_row() {
+    let nesti = Nesti::new();
+    nesti.put(
+        "import",
+        Sharded {
+            done: 50,
+            shards: vec![30, 20],
+        },
+    );
+
+    assert_tree(
+        &nesti,
+        "
+        import                      ━━━━━━━━━━┄┄┄┄┄┄┄┄┄┄ 50.0%
+        |- shard-0                                          30
+        `- shard-1                                          20
+        ",
+    );
+}
+
+#[test]
+fn children_not_put_again_are_removed() {
+    let nesti = Nesti::new();
+    nesti.put(
+        "import",
+        Sharded {
+            done: 50,
+            shards: vec![30, 20],
+        },
+    );
+    nesti.put(
+        "import",
+        Sharded {
+            done: 60,
+            shards: vec![60],
+        },
+    );
+
+    assert_eq!(
+        nesti.paths().collect::<Vec<_>>(),
+        ["import", "import/shard-0"]
+    );
+}
+
+/// Puts a running timer below its own path.
+struct Timed;
+
+impl Element for Timed {
+    fn children(&self, children: &mut Children) {
+        children.put("elapsed", &Timer(Second));
+    }
+}
+
+#[test]
+fn children_are_refreshed_on_flush() {
+    let clock = ManualClock::new();
+    let nesti = Nesti::with_clock(clock.clone());
+    nesti.put("job", Timed);
+
+    clock.advance(Duration::from_secs(3));
+
+    assert_tree(
+        &nesti,
+        "
+        job
+        `- elapsed                    3s
+        ",
+    );
+}
@@ -0,0 +1,61 @@
+use std::time::Duration;
+
+use nesti::{
+    testing::{assert_tree, render},
+    EndTimer, ManualClock, Millis, Nesti, Progress, Second, Timer,
+};
+
+#[test]
+fn timer_reads_manual_clock() {
+    let clock = ManualClock::new();
+    let nesti = Nesti::with_clock(clock.clone());
+    nesti.put("uptime", Timer(Second));
+
+    clock.advance(Duration::from_secs(75));
+
+    assert_tree(
+        &nesti,
+        "
+        uptime                        1m  15s
+        ",
+    );
+}
+
+#[test]
+fn end_timer_freezes_elapsed_time() {
+    let clock = ManualClock::new();
+    let nesti = Nesti::with_clock(clock.clone());
+    nesti.put("job", Timer(Millis));
+
+    clock.advance(Duration::from_millis(1500));
+    render(&nesti);
+    nesti.put("job", EndTimer);
+    clock.advance(Duration::from_secs(10));
+
+    assert_tree(
+        &nesti,
+        "
+        job                           1s 500ms
+        ",
+    );
+}
+
+#[test]
+fn progress_rate_reads_manual_clock() {
+    let clock = ManualClock::new();
+    let nesti = Nesti::with_clock(clock.clone());
+    let progress = |current| Progress {
+        current,
+        maximum: 100,
+        show_percent: false,
+        show_values: false,
+        show_rate: true,
+    };
+
+    nesti.put("download", progress(0));
+    clock.advance(Duration::from_secs(4));
+    nesti.put("download", progress(10));
+
+    let snapshot = nesti.get("download").unwrap();
+    assert!(snapshot.content.unwrap().ends_with(" 2.5/s"));
+}
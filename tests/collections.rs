@@ -0,0 +1,138 @@
+use std::collections::{BTreeMap, HashMap};
+
+use nesti::{testing::assert_tree, Entries, Inline, Integer, Nesti};
+
+#[test]
+fn vec_items_become_numbered_children() {
+    let nesti = Nesti::new();
+    nesti.put("regions", vec!["eu-west", "us-east", "ap-south"]);
+
+    assert_tree(
+        &nesti,
+        "
+        regions
+        |- 0                         eu-west
+        |- 1                         us-east
+        `- 2                        ap-south
+        ",
+    );
+    assert_eq!(
+        nesti.paths().collect::<Vec<_>>(),
+        ["regions", "regions/0", "regions/1", "regions/2"]
+    );
+}
+
+#[test]
+fn shrinking_list_removes_stale_children() {
+    let nesti = Nesti::new();
+    nesti.put("queue", vec![Integer(1), Integer(2), Integer(3)]);
+    nesti.put("queue", vec![Integer(4)]);
+
+    assert_tree(
+        &nesti,
+        "
+        queue
+        `- 0                        4
+        ",
+    );
+}
+
+#[test]
+fn replacing_list_with_value_removes_children() {
+    let nesti = Nesti::new();
+    nesti.put("queue", [Integer(1), Integer(2)]);
+    nesti.put("queue", "empty");
+
+    assert_tree(
+        &nesti,
+        "
+        queue                       empty
+        ",
+    );
+}
+
+#[test]
+fn nested_lists() {
+    let nesti = Nesti::new();
+    nesti.put("grid", vec![vec!["a", "b"], vec!["c"]]);
+
+    assert_eq!(
+        nesti.paths().collect::<Vec<_>>(),
+        ["grid", "grid/0", "grid/0/0", "grid/0/1", "grid/1", "grid/1/0"]
+    );
+}
+
+#[test]
+fn inline_joins_items_on_one_row() {
+    let nesti = Nesti::new();
+    nesti.put("queue", vec![Integer(1), Integer(2)]);
+    nesti.put("queue", Inline(vec![Integer(1), Integer(1000)]));
+
+    assert_tree(
+        &nesti,
+        "
+        queue                       1, 1,000
+        ",
+    );
+}
+
+#[test]
+fn btree_map_children_follow_key_order() {
+    let nesti = Nesti::new();
+    nesti.put(
+        "hosts",
+        BTreeMap::from([("b", Integer(2)), ("c", Integer(3))]),
+    );
+    nesti.put(
+        "hosts",
+        BTreeMap::from([("a", Integer(1)), ("b", Integer(2)), ("c", Integer(3))]),
+    );
+
+    assert_eq!(
+        nesti.children("hosts").collect::<Vec<_>>(),
+        ["hosts/a", "hosts/b", "hosts/c"]
+    );
+}
+
+#[test]
+fn hash_map_keeps_first_seen_order_and_drops_vanished_keys() {
+    let nesti = Nesti::new();
+    nesti.put("jobs", HashMap::from([("build", "running")]));
+    nesti.put(
+        "jobs",
+        HashMap::from([("build", "done"), ("deploy", "queued")]),
+    );
+    nesti.put(
+        "jobs",
+        HashMap::from([("deploy", "running"), ("test", "queued")]),
+    );
+
+    let mut children = nesti.children("jobs").collect::<Vec<_>>();
+    assert_eq!(children.remove(0), "jobs/deploy");
+    assert_eq!(children, ["jobs/test"]);
+    assert_eq!(
+        nesti.get("jobs/deploy").unwrap().content.as_deref(),
+        Some("running")
+    );
+}
+
+#[test]
+fn entries_follow_insertion_order() {
+    let nesti = Nesti::new();
+    nesti.put("jobs", Entries(vec![("test", "queued"), ("build", "done")]));
+    nesti.put(
+        "jobs",
+        Entries(vec![
+            ("deploy", "queued"),
+            ("build", "done"),
+            ("test", "running"),
+        ]),
+    );
+
+    assert_eq!(
+        nesti.children("jobs").collect::<Vec<_>>(),
+        ["jobs/deploy", "jobs/build", "jobs/test"]
+    );
+    nesti.put("jobs", Entries(vec![("test", "passed")]));
+    assert_eq!(nesti.children("jobs").collect::<Vec<_>>(), ["jobs/test"]);
+}
@@ -0,0 +1,80 @@
+use nesti::{
+    style::{Color, Palette16},
+    Background, ColorSupport, Cyan, Indexed, Nesti, RenderOptions, Rgb, Text,
+};
+
+fn render(nesti: &Nesti, support: ColorSupport) -> String {
+    nesti.render_to_string(RenderOptions::new().color_support(support))
+}
+
+#[test]
+fn rgb_is_downgraded_to_the_colour_support() {
+    let nesti = Nesti::new();
+    nesti.put("temp", Rgb(255, 128, 0, Text("warm")));
+
+    assert!(render(&nesti, ColorSupport::TrueColor).contains(";38;2;255;128;0mwarm"));
+    assert!(render(&nesti, ColorSupport::Ansi256).contains(";38;5;208mwarm"));
+    assert!(render(&nesti, ColorSupport::Ansi16).contains(";33mwarm"));
+    assert!(!render(&nesti, ColorSupport::None).contains('\x1b'));
+}
+
+#[test]
+fn indexed_and_background_colours() {
+    let nesti = Nesti::new();
+    nesti.put("load", Indexed(208, Text("high")));
+    nesti.put(
+        "state",
+        Background(Color::try_from(Palette16::Red).unwrap(), Cyan(Text("down"))),
+    );
+    nesti.put("grey", Background(Color::Rgb(40, 40, 40), Text("idle")));
+
+    let truecolor = render(&nesti, ColorSupport::TrueColor);
+    assert!(truecolor.contains(";38;5;208mhigh"));
+    assert!(truecolor.contains(";36;41mdown"));
+    assert!(truecolor.contains(";48;2;40;40;40midle"));
+
+    let ansi16 = render(&nesti, ColorSupport::Ansi16);
+    assert!(ansi16.contains(";33mhigh"));
+    assert!(ansi16.contains(";40midle"));
+}
+
+#[test]
+fn escapes_keep_columns_aligned() {
+    let nesti = Nesti::new();
+    nesti.put("a", Rgb(1, 2, 3, Text("x")));
+    nesti.put("a/b", ("plain", Indexed(99, Text("yy"))));
+    nesti.put("a/c", (Cyan(Text("zzz")), "w"));
+
+    let plain = nesti.render_to_string(RenderOptions::new().strip_ansi());
+    for support in [
+        ColorSupport::TrueColor,
+        ColorSupport::Ansi256,
+        ColorSupport::Ansi16,
+    ] {
+        assert_eq!(strip(&render(&nesti, support)), plain);
+    }
+}
+
+/// `s` without SGR sequences.
+fn strip(s: &str) -> String {
+    let mut out = String::new();
+    let mut chars = s.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            chars.by_ref().find(|&c| c == 'm');
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+#[test]
+fn palette_default_is_no_color() {
+    assert_eq!(
+        Color::try_from(Palette16::Blue).ok(),
+        Some(Color::Indexed(4))
+    );
+    assert!(Color::try_from(Palette16::Default).is_err());
+    assert!(Color::try_from(Palette16::Hidden).is_err());
+}
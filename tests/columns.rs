@@ -0,0 +1,89 @@
+use std::time::Duration;
+
+use nesti::{
+    testing::assert_tree, Align, Column, Integer, ManualClock, Nesti, Second, Text, Timer,
+};
+
+#[test]
+fn tuple_fills_a_column_each() {
+    let nesti = Nesti::new();
+    nesti.put("jobs/build", (Integer(42), Text("running"), Text("2m")));
+    nesti.put("jobs/test", (Integer(7), Text("queued")));
+
+    assert_tree(
+        &nesti,
+        "
+        jobs
+        |- build                    42 running 2m
+        `- test                      7  queued
+        ",
+    );
+    assert_eq!(nesti.get("jobs/build").unwrap().cells, ["running", "2m"]);
+}
+
+#[test]
+fn headers_and_alignment() {
+    let nesti = Nesti::new();
+    nesti.set_columns([
+        Column::new().header("done"),
+        Column::new()
+            .header("status")
+            .align(Align::Left)
+            .min_width(8),
+    ]);
+    nesti.put("jobs/build", (Integer(42), Text("ok")));
+
+    assert_tree(
+        &nesti,
+        "
+                                    done status
+        jobs
+        `- build                      42 ok
+        ",
+    );
+}
+
+#[test]
+fn max_width_cuts_values_short() {
+    let nesti = Nesti::new();
+    nesti.set_columns([Column::new(), Column::new().max_width(6)]);
+    nesti.put("job", (Integer(1), Text("waiting for runner")));
+
+    assert_tree(
+        &nesti,
+        "
+        job                         1 wai...
+        ",
+    );
+}
+
+#[test]
+fn replacing_tuple_drops_extra_columns() {
+    let nesti = Nesti::new();
+    nesti.put("job", (Integer(1), Text("running")));
+    nesti.put("job", Integer(2));
+
+    assert!(nesti.get("job").unwrap().cells.is_empty());
+    assert_tree(
+        &nesti,
+        "
+        job                         2
+        ",
+    );
+}
+
+#[test]
+fn columns_are_refreshed_on_flush() {
+    let clock = ManualClock::new();
+    let nesti = Nesti::with_clock(clock.clone());
+    nesti.put("job", (Text("running"), Timer(Second)));
+
+    clock.advance(Duration::from_secs(5));
+
+    assert_tree(
+        &nesti,
+        "
+        job                         running   5s
+        ",
+    );
+}
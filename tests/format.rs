@@ -0,0 +1,99 @@
+use nesti::{Decimal, DecimalUnit, Integer, Locale, Nesti, NumberFormat, NumericValue};
+
+fn content(nesti: &Nesti, path: &str) -> String {
+    nesti.get(path).unwrap().content.unwrap()
+}
+
+#[test]
+fn defaults_match_previous_output() {
+    let nesti = Nesti::new();
+    nesti.put("int", Integer(1234567u64));
+    nesti.put("dec", Decimal(-1234.5f64));
+    nesti.put("time", DecimalUnit(1500.0f64, "ms"));
+
+    assert_eq!(content(&nesti, "int"), "1,234,567");
+    assert_eq!(content(&nesti, "dec"), "-1234.50");
+    assert_eq!(content(&nesti, "time"), "1500.00 ms");
+}
+
+#[test]
+fn precision_and_locale() {
+    let nesti = Nesti::new();
+    let format = NumberFormat::new()
+        .precision(4)
+        .locale(Locale::de)
+        .group_decimals(true);
+    nesti.put("dec", Decimal(1234567.891f64).format(format));
+    nesti.put("int", Integer(1234567u64).format(format));
+    nesti.put(
+        "none",
+        Decimal(2.5f64).format(NumberFormat::new().precision(0)),
+    );
+
+    assert_eq!(content(&nesti, "dec"), "1.234.567,8910");
+    assert_eq!(content(&nesti, "int"), "1.234.567");
+    assert_eq!(content(&nesti, "none"), "2");
+}
+
+#[test]
+fn scientific_and_auto_notation() {
+    let nesti = Nesti::new();
+    nesti.put(
+        "sci",
+        Decimal(1234.5f64).format(NumberFormat::new().scientific()),
+    );
+    nesti.put(
+        "tiny",
+        Decimal(0.000012f64).format(NumberFormat::new().auto()),
+    );
+    nesti.put("normal", Decimal(0.5f64).format(NumberFormat::new().auto()));
+
+    assert_eq!(content(&nesti, "sci"), "1.23e3");
+    assert_eq!(content(&nesti, "tiny"), "1.20e-5");
+    assert_eq!(content(&nesti, "normal"), "0.50");
+}
+
+#[test]
+fn compact_notation_uses_si_prefixes() {
+    let nesti = Nesti::new();
+    let format = NumberFormat::new().precision(1).compact();
+    nesti.put("small", Integer(999u32).format(format));
+    nesti.put("k", Integer(1234u32).format(format));
+    nesti.put("m", Decimal(3_400_000.0f64).format(format));
+    nesti.put("rounded", Integer(999_999u32).format(format));
+
+    assert_eq!(content(&nesti, "small"), "999");
+    assert_eq!(content(&nesti, "k"), "1.2k");
+    assert_eq!(content(&nesti, "m"), "3.4M");
+    assert_eq!(content(&nesti, "rounded"), "1.0M");
+}
+
+#[test]
+fn instance_default_applies_to_existing_numbers() {
+    let nesti = Nesti::new();
+    nesti.put("dec", Decimal(1.5f64));
+    nesti.put(
+        "own",
+        Decimal(1.5f64).format(NumberFormat::new().precision(1)),
+    );
+
+    nesti.set_number_format(NumberFormat::new().precision(3));
+    nesti.render_to_string(Default::default());
+
+    assert_eq!(content(&nesti, "dec"), "1.500");
+    assert_eq!(content(&nesti, "own"), "1.5");
+}
+
+#[test]
+fn format_survives_deltas() {
+    let nesti = Nesti::new();
+    let format = NumberFormat::new().compact();
+    nesti.put("bytes", Integer(1000u64).format(format));
+    nesti.put("bytes", Integer(500u64).add());
+
+    assert_eq!(content(&nesti, "bytes"), "1.50k");
+    assert_eq!(
+        nesti.get("bytes").unwrap().value,
+        Some(NumericValue::U64(1500))
+    );
+}
@@ -0,0 +1,48 @@
+use nesti::{Glyphs, Integer, Nesti, RenderConfig, RenderOptions};
+
+fn tree(config: RenderConfig) -> Nesti {
+    let nesti = Nesti::new();
+    nesti.set_render_config(config);
+    nesti.put("jobs/queued", Integer(3));
+    nesti.put("jobs/running/build", Integer(1));
+    nesti.put("jobs/running/test", Integer(2));
+    nesti
+}
+
+#[test]
+fn heavy_glyphs() {
+    let nesti = tree(RenderConfig::new().glyphs(Glyphs::HEAVY));
+
+    let out = nesti.render_to_string(RenderOptions::new().strip_ansi());
+    assert_eq!(
+        out,
+        "\n   jobs                         \n   ┣━ queued                   3\n   ┗━ running                   \n      ┣━ build                 1\n      ┗━ test                  2\n\n"
+    );
+}
+
+#[test]
+fn ascii_option_overrides_glyphs() {
+    let nesti = tree(RenderConfig::new().glyphs(Glyphs::HEAVY));
+
+    let out = nesti.render_to_string(RenderOptions::plain());
+    assert_eq!(
+        out,
+        "\n   jobs                         \n   |- queued                   3\n   `- running                   \n      |- build                 1\n      `- test                  2\n\n"
+    );
+}
+
+#[test]
+fn indent_width_and_padding() {
+    let nesti = tree(
+        RenderConfig::new()
+            .indent(0)
+            .label_min_width(12)
+            .padding(0, 1),
+    );
+
+    let out = nesti.render_to_string(RenderOptions::plain());
+    assert_eq!(
+        out,
+        "jobs          \n|- queued    3\n`- running    \n   |- build  1\n   `- test   2\n\n\n"
+    );
+}
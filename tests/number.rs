@@ -0,0 +1,277 @@
+use std::collections::{BTreeMap, HashMap};
+
+use nesti::{
+    style::TextFg,
+    testing::{assert_tree, render},
+    Cyan, Decimal, DecimalDelta, DecimalUnit, Integer, IntegerDelta, IntegerUnit, Nesti,
+    NumericValue, Text,
+};
+
+#[test]
+fn delta_applies_once_across_flushes() {
+    let nesti = Nesti::new();
+    nesti.put("processes/adder", Integer(1u32));
+    nesti.put("processes/adder", Integer(1u32).add());
+
+    for _ in 0..3 {
+        render(&nesti);
+    }
+
+    let snapshot = nesti.get("processes/adder").unwrap();
+    assert_eq!(snapshot.content.as_deref(), Some("2"));
+    assert!(matches!(snapshot.value, Some(NumericValue::U32(2))));
+}
+
+#[test]
+fn delta_held_by_a_stored_element_applies_once() {
+    let nesti = Nesti::new();
+    nesti.put("t", (Integer(1u64), Text("x")));
+    nesti.put("t", (Integer(1u64).add(), Text("x")));
+    nesti.put("cell", (Text("x"), Integer(1u64)));
+    nesti.put("cell", (Text("x"), Integer(1u64).add()));
+    nesti.put("v", vec![Integer(1u64)]);
+    nesti.put("v", vec![Integer(1u64).add()]);
+    nesti.put("m", BTreeMap::from([("a", Integer(5u64))]));
+    nesti.put("m", BTreeMap::from([("a", Integer(5u64).add())]));
+    nesti.put("h", HashMap::from([("a", Integer(5u64))]));
+    nesti.put("h", HashMap::from([("a", Integer(5u64).add())]));
+
+    for _ in 0..3 {
+        render(&nesti);
+    }
+
+    assert_eq!(nesti.get("t").unwrap().content.as_deref(), Some("2"));
+    assert_eq!(nesti.get("cell").unwrap().cells, ["2"]);
+    assert_eq!(nesti.get("v/0").unwrap().content.as_deref(), Some("2"));
+    assert_eq!(nesti.get("m/a").unwrap().content.as_deref(), Some("10"));
+    assert_eq!(nesti.get("h/a").unwrap().content.as_deref(), Some("10"));
+}
+
+#[test]
+fn delta_keeps_previous_element_kind() {
+    let nesti = Nesti::new();
+    nesti.put("load", Decimal(1.5f64));
+    nesti.put("load", Decimal(2.0f64).mul());
+    render(&nesti);
+
+    let snapshot = nesti.get("load").unwrap();
+    assert_eq!(snapshot.content.as_deref(), Some("3.00"));
+    assert!(snapshot.kind.unwrap().contains("Decimal<f64>"));
+}
+
+#[test]
+fn delta_on_empty_path_starts_from_delta() {
+    let nesti = Nesti::new();
+    nesti.put("count", Integer(3i64).add());
+    render(&nesti);
+    render(&nesti);
+
+    assert_eq!(nesti.get("count").unwrap().content.as_deref(), Some("3"));
+}
+
+#[test]
+fn checked_underflow_shows_error_and_keeps_value() {
+    let nesti = Nesti::new();
+    nesti.put("queue", Integer(0u64));
+    nesti.put("queue", Integer(1u64).sub());
+
+    assert_tree(
+        &nesti,
+        "
+        queue                       overflow
+        ",
+    );
+    let snapshot = nesti.get("queue").unwrap();
+    assert!(matches!(snapshot.value, Some(NumericValue::U64(0))));
+    assert_eq!(snapshot.error.as_deref(), Some("overflow"));
+}
+
+#[test]
+fn next_put_clears_error() {
+    let nesti = Nesti::new();
+    nesti.put("queue", Integer(0u64));
+    nesti.put("queue", Integer(0u64).div());
+    assert_eq!(
+        nesti.get("queue").unwrap().error.as_deref(),
+        Some("division by zero")
+    );
+
+    nesti.put("queue", Integer(2u64).add());
+
+    let snapshot = nesti.get("queue").unwrap();
+    assert_eq!(snapshot.error, None);
+    assert_eq!(snapshot.content.as_deref(), Some("2"));
+}
+
+#[test]
+fn saturating_and_wrapping_deltas() {
+    let nesti = Nesti::new();
+    nesti.put("saturating", Integer(250u8));
+    nesti.put("saturating", Integer(10u8).add().saturating());
+    nesti.put("wrapping", Integer(250u8));
+    nesti.put("wrapping", Integer(10u8).add().wrapping());
+
+    assert_eq!(
+        nesti.get("saturating").unwrap().content.as_deref(),
+        Some("255")
+    );
+    assert_eq!(nesti.get("wrapping").unwrap().content.as_deref(), Some("4"));
+}
+
+#[test]
+fn delta_variants_can_be_built_directly() {
+    let nesti = Nesti::new();
+    nesti.put("count", Integer(250u8));
+    nesti.put("count", IntegerDelta::Add(3u8));
+    nesti.put("load", Decimal(1.5f64));
+    nesti.put("load", DecimalDelta::Mul(2.0f64));
+
+    assert_eq!(nesti.get("count").unwrap().content.as_deref(), Some("253"));
+    assert_eq!(nesti.get("load").unwrap().content.as_deref(), Some("3.00"));
+
+    nesti.put("count", IntegerDelta::Add(10u8).saturating());
+    assert_eq!(nesti.get("count").unwrap().content.as_deref(), Some("255"));
+}
+
+#[test]
+fn missing_value_counts_as_zero() {
+    let nesti = Nesti::new();
+    nesti.put("a", Integer(5i32).sub());
+    nesti.put("b", Decimal(2.0f64).mul());
+
+    assert_eq!(nesti.get("a").unwrap().content.as_deref(), Some("-5"));
+    assert_eq!(nesti.get("b").unwrap().content.as_deref(), Some("0.00"));
+}
+
+#[test]
+fn decimal_overflow_is_checked() {
+    let nesti = Nesti::new();
+    nesti.put("x", Decimal(f64::MAX));
+    nesti.put("x", Decimal(2.0f64).mul());
+    assert_eq!(nesti.get("x").unwrap().error.as_deref(), Some("overflow"));
+
+    nesti.put("x", Decimal(2.0f64).mul().saturating());
+    assert!(matches!(
+        nesti.get("x").unwrap().value,
+        Some(NumericValue::F64(v)) if v == f64::MAX
+    ));
+}
+
+#[test]
+fn delta_keeps_stored_integer_type() {
+    let nesti = Nesti::new();
+    nesti.put("bytes", Integer(5u64));
+    nesti.put("bytes", Integer(1u32).add());
+    nesti.put("bytes", Integer(2i8).mul());
+
+    assert_eq!(
+        nesti.get("bytes").unwrap().value,
+        Some(NumericValue::U64(12))
+    );
+}
+
+#[test]
+fn operand_out_of_range_for_stored_type() {
+    let nesti = Nesti::new();
+    nesti.put("small", Integer(1u8));
+    nesti.put("small", Integer(300u32).add());
+    assert_eq!(
+        nesti.get("small").unwrap().error.as_deref(),
+        Some("overflow")
+    );
+
+    nesti.put("small", Integer(1000u32).add().saturating());
+    assert_eq!(
+        nesti.get("small").unwrap().value,
+        Some(NumericValue::U8(255))
+    );
+}
+
+#[test]
+fn mixing_integer_and_decimal_gives_decimal() {
+    let nesti = Nesti::new();
+    nesti.put("load", Integer(3u32));
+    nesti.put("load", Decimal(0.5f64).add());
+    assert_eq!(
+        nesti.get("load").unwrap().value,
+        Some(NumericValue::F64(3.5))
+    );
+
+    nesti.put("load", Integer(2i64).mul());
+    let snapshot = nesti.get("load").unwrap();
+    assert_eq!(snapshot.value, Some(NumericValue::F64(7.0)));
+    assert_eq!(snapshot.content.as_deref(), Some("7.00"));
+}
+
+#[test]
+fn max_tracks_high_water_mark() {
+    let nesti = Nesti::new();
+    for depth in [3u32, 9, 4] {
+        nesti.put("queue/peak", Integer(depth).max());
+    }
+    nesti.put("latency/min", Decimal(0.8f64));
+    nesti.put("latency/min", Decimal(1.2f64).min());
+    nesti.put("latency/min", Decimal(0.3f64).min());
+
+    assert_eq!(
+        nesti.get("queue/peak").unwrap().value,
+        Some(NumericValue::U32(9))
+    );
+    assert_eq!(
+        nesti.get("latency/min").unwrap().value,
+        Some(NumericValue::F64(0.3))
+    );
+}
+
+#[test]
+fn reset_keeps_stored_type() {
+    let nesti = Nesti::new();
+    nesti.put("count", Integer(42u64));
+    nesti.put("count", Integer(0i32).reset());
+
+    assert_eq!(
+        nesti.get("count").unwrap().value,
+        Some(NumericValue::U64(0))
+    );
+}
+
+#[test]
+fn compare_and_set_only_when_expected() {
+    let nesti = Nesti::new();
+    nesti.put("state", Integer(1u8));
+    nesti.put("state", Integer(2u8).compare_and_set(5));
+    assert_eq!(nesti.get("state").unwrap().value, Some(NumericValue::U8(1)));
+
+    nesti.put("state", Integer(1u8).compare_and_set(5));
+    assert_eq!(nesti.get("state").unwrap().value, Some(NumericValue::U8(5)));
+}
+
+#[test]
+fn deltas_keep_unit_and_style() {
+    let nesti = Nesti::new();
+    nesti.put("latency", Cyan(DecimalUnit(1.5f64, "ms")));
+    nesti.put("latency", Decimal(0.25f64).add());
+    nesti.put("disk", IntegerUnit(10u64, "GiB"));
+    nesti.put("disk", IntegerUnit(2u64, "GiB").mul());
+    render(&nesti);
+
+    let latency = nesti.get("latency").unwrap();
+    assert_eq!(latency.content.as_deref(), Some("1.75 ms"));
+    assert_eq!(latency.unit.as_deref(), Some("ms"));
+    assert!(latency.style.unwrap().get::<TextFg>().is_some());
+
+    let disk = nesti.get("disk").unwrap();
+    assert_eq!(disk.content.as_deref(), Some("20 GiB"));
+    assert_eq!(disk.value, Some(NumericValue::U64(20)));
+}
+
+#[test]
+fn plain_number_drops_previous_unit() {
+    let nesti = Nesti::new();
+    nesti.put("disk", IntegerUnit(10u64, "GiB"));
+    nesti.put("disk", Integer(3u64));
+
+    let disk = nesti.get("disk").unwrap();
+    assert_eq!(disk.content.as_deref(), Some("3"));
+    assert_eq!(disk.unit, None);
+}
@@ -0,0 +1,88 @@
+use nesti::{
+    nesti, nesti_flush, nesti_pop_matching, nesti_writer,
+    testing::{assert_tree, SharedBuffer},
+    Integer, Nesti,
+};
+
+fn workers() -> Nesti {
+    let nesti = Nesti::new();
+    nesti.put("workers/1/status", "idle");
+    nesti.put("workers/1/jobs", Integer(3));
+    nesti.put("workers/2/status", "busy");
+    nesti.put("workers/10/status", "idle");
+    nesti.put("queue", Integer(4));
+    nesti
+}
+
+#[test]
+fn select_lists_matching_paths_in_tree_order() {
+    let nesti = workers();
+
+    assert_eq!(
+        nesti.select("workers/*/status").collect::<Vec<_>>(),
+        ["workers/1/status", "workers/2/status", "workers/10/status"]
+    );
+    assert_eq!(
+        nesti.select("workers/?/*").collect::<Vec<_>>(),
+        ["workers/1/status", "workers/1/jobs", "workers/2/status"]
+    );
+    assert_eq!(
+        nesti.select("**/jobs").collect::<Vec<_>>(),
+        ["workers/1/jobs"]
+    );
+}
+
+#[test]
+fn select_skips_nodes_without_a_value() {
+    let nesti = workers();
+
+    assert!(nesti.select("workers/*").next().is_none());
+    assert_eq!(nesti.select("*").collect::<Vec<_>>(), ["queue"]);
+}
+
+#[test]
+fn pop_matching_removes_matches_and_their_subtrees() {
+    let nesti = workers();
+    nesti.pop_matching("workers/?");
+
+    assert_tree(
+        &nesti,
+        "
+        workers
+        `- 10
+           `- status                idle
+        queue                          4
+        ",
+    );
+}
+
+#[test]
+fn pop_matching_with_double_star() {
+    let nesti = workers();
+    nesti.pop_matching("**/status");
+
+    assert_tree(
+        &nesti,
+        "
+        workers
+        `- 1
+           `- jobs                  3
+        queue                       4
+        ",
+    );
+}
+
+#[test]
+fn global_pop_matching() {
+    let buffer = SharedBuffer::new();
+    nesti_writer(buffer.clone());
+    nesti("global/a/tmp", Integer(1));
+    nesti("global/b/tmp", Integer(2));
+    nesti("global/b/keep", Integer(3));
+    nesti_pop_matching("global/*/tmp");
+    nesti_flush().unwrap();
+
+    let out = buffer.contents();
+    assert!(out.contains("keep"));
+    assert!(!out.contains("tmp"));
+}
@@ -0,0 +1,82 @@
+use std::{collections::BTreeMap, time::Duration};
+
+use nesti::{testing::SharedBuffer, Integer, ManualClock, Nesti, PlainOutput};
+
+const INTERVAL: Duration = Duration::from_secs(5);
+
+fn plain(output: PlainOutput) -> (Nesti, ManualClock, SharedBuffer) {
+    let clock = ManualClock::new();
+    let buffer = SharedBuffer::new();
+    let nesti = Nesti::with_writer(buffer.clone());
+    nesti.set_clock(clock.clone());
+    nesti.set_plain_output(output);
+    nesti.set_plain_interval(INTERVAL);
+    (nesti, clock, buffer)
+}
+
+#[test]
+fn snapshot_appends_whole_tree_once_per_interval() {
+    let (nesti, clock, buffer) = plain(PlainOutput::Snapshot);
+    nesti.put("jobs/build", Integer(1));
+    nesti.flush().unwrap();
+    assert_eq!(buffer.contents().matches("build").count(), 1);
+
+    // Within the interval nothing is appended
+    nesti.put("jobs/build", Integer(2));
+    nesti.flush().unwrap();
+    assert_eq!(buffer.contents().matches("build").count(), 1);
+
+    clock.advance(INTERVAL);
+    nesti.flush().unwrap();
+    let out = buffer.contents();
+    assert_eq!(out.matches("build").count(), 2);
+    assert!(out.contains('2'));
+    assert!(!out.contains('\x1b'));
+}
+
+#[test]
+fn snapshot_skips_unchanged_tree() {
+    let (nesti, clock, buffer) = plain(PlainOutput::Snapshot);
+    nesti.put("jobs/build", Integer(1));
+    nesti.flush().unwrap();
+
+    clock.advance(INTERVAL);
+    nesti.flush().unwrap();
+    assert_eq!(buffer.contents().matches("build").count(), 1);
+}
+
+#[test]
+fn changes_reports_rows_by_path() {
+    let (nesti, clock, buffer) = plain(PlainOutput::Changes);
+    nesti.put(
+        "jobs",
+        BTreeMap::from([("a", Integer(1)), ("c", Integer(3))]),
+    );
+    nesti.flush().unwrap();
+    buffer.clear();
+
+    // Inserting a row in between leaves the rows after it unreported
+    nesti.put(
+        "jobs",
+        BTreeMap::from([("a", Integer(10)), ("b", Integer(2)), ("c", Integer(3))]),
+    );
+    clock.advance(INTERVAL);
+    nesti.flush().unwrap();
+
+    assert_eq!(buffer.contents(), "jobs/a: 10\njobs/b: 2\n");
+}
+
+#[test]
+fn changes_reports_removed_rows() {
+    let (nesti, clock, buffer) = plain(PlainOutput::Changes);
+    nesti.put("jobs/a", Integer(1));
+    nesti.put("jobs/b", Integer(2));
+    nesti.flush().unwrap();
+    buffer.clear();
+
+    nesti.pop("jobs/a");
+    clock.advance(INTERVAL);
+    nesti.flush().unwrap();
+
+    assert_eq!(buffer.contents(), "jobs/a (removed)\n");
+}
@@ -0,0 +1,92 @@
+use nesti::{
+    nesti, nesti_flush, nesti_pop_children, nesti_pop_exact, nesti_writer,
+    testing::{assert_tree, SharedBuffer},
+    Integer, Nesti,
+};
+
+fn jobs() -> Nesti {
+    let nesti = Nesti::new();
+    nesti.put("jobs", Integer(2));
+    nesti.put("jobs/build", Integer(1));
+    nesti.put("jobs/build/step", Integer(4));
+    nesti.put("jobs/test", Integer(3));
+    nesti
+}
+
+#[test]
+fn pop_exact_keeps_descendants() {
+    let nesti = jobs();
+    nesti.pop_exact("jobs/build");
+
+    assert!(nesti.get("jobs/build").is_none());
+    assert!(nesti.get("jobs/build/step").is_some());
+    assert_tree(
+        &nesti,
+        "
+        jobs                        2
+        |- build
+        |  `- step                  4
+        `- test                     3
+        ",
+    );
+}
+
+#[test]
+fn pop_exact_on_leaf_removes_it() {
+    let nesti = jobs();
+    nesti.pop_exact("jobs/test");
+
+    assert_eq!(
+        nesti.paths().collect::<Vec<_>>(),
+        ["jobs", "jobs/build", "jobs/build/step"]
+    );
+}
+
+#[test]
+fn pop_children_keeps_the_node() {
+    let nesti = jobs();
+    nesti.pop_children("jobs");
+
+    assert_eq!(nesti.paths().collect::<Vec<_>>(), ["jobs"]);
+    assert_tree(
+        &nesti,
+        "
+        jobs                        2
+        ",
+    );
+}
+
+#[test]
+fn pop_children_removes_the_whole_subtree() {
+    let nesti = jobs();
+    nesti.pop_children("jobs/build");
+
+    assert!(nesti.get("jobs/build").is_some());
+    assert!(nesti.get("jobs/build/step").is_none());
+
+    // Children put again start fresh
+    nesti.put("jobs/build/step", Integer(1).add());
+    assert_eq!(
+        nesti.get("jobs/build/step").unwrap().content.as_deref(),
+        Some("1")
+    );
+}
+
+#[test]
+fn global_pop_exact_and_pop_children() {
+    let buffer = SharedBuffer::new();
+    nesti_writer(buffer.clone());
+    nesti("global/parent", Integer(1));
+    nesti("global/parent/kept", Integer(2));
+    nesti("global/other", Integer(3));
+    nesti("global/other/dropped", Integer(4));
+    nesti_pop_exact("global/parent");
+    nesti_pop_children("global/other");
+    nesti_flush().unwrap();
+
+    let out = buffer.contents();
+    assert!(out.contains("kept"));
+    assert!(out.contains("other"));
+    assert!(!out.contains("dropped"));
+    assert!(!out.contains('1'));
+}
@@ -0,0 +1,204 @@
+use nesti::{testing::assert_tree, Integer, Nesti, RenderOptions};
+use unicode_width::UnicodeWidthStr;
+
+#[test]
+fn empty_tree_renders_nothing() {
+    let nesti = Nesti::new();
+
+    assert_eq!(nesti.render_to_string(RenderOptions::plain()), "");
+}
+
+#[test]
+fn single_root_with_value() {
+    let nesti = Nesti::new();
+    nesti.put("uptime", "3s");
+
+    assert_tree(
+        &nesti,
+        "
+        uptime                      3s
+        ",
+    );
+}
+
+#[test]
+fn junctions_and_corners() {
+    let nesti = Nesti::new();
+    nesti.put("system/cpu", "amd64");
+    nesti.put("system/memory/total", "16 GiB");
+    nesti.put("system/memory/free", "4 GiB");
+    nesti.put("system/online", true);
+
+    assert_tree(
+        &nesti,
+        "
+        system
+        |- cpu                        amd64
+        |- memory
+        |  |- total                  16 GiB
+        |  `- free                    4 GiB
+        `- online                    TRUE ●
+        ",
+    );
+}
+
+#[test]
+fn corner_branch_is_not_continued() {
+    let nesti = Nesti::new();
+    nesti.put("a/b/c", "1");
+    nesti.put("a/b/d", "2");
+
+    assert_tree(
+        &nesti,
+        "
+        a
+        `- b
+           |- c                     1
+           `- d                     2
+        ",
+    );
+}
+
+#[test]
+fn parent_with_value_and_children() {
+    let nesti = Nesti::new();
+    nesti.put("jobs", Integer(2));
+    nesti.put("jobs/1", "running");
+    nesti.put("jobs/2", "queued");
+
+    assert_tree(
+        &nesti,
+        "
+        jobs                              2
+        |- 1                        running
+        `- 2                         queued
+        ",
+    );
+}
+
+#[test]
+fn rows_are_grouped_under_first_insertion() {
+    let nesti = Nesti::new();
+    nesti.put("system/cpu", "amd64");
+    nesti.put("processes/total", Integer(10));
+    nesti.put("system/timers/1", "1s");
+
+    assert_tree(
+        &nesti,
+        "
+        system
+        |- cpu                      amd64
+        `- timers
+           `- 1                        1s
+        processes
+        `- total                       10
+        ",
+    );
+}
+
+#[test]
+fn pop_keeps_sibling_with_shared_prefix() {
+    let nesti = Nesti::new();
+    nesti.put("jobs/1", "a");
+    nesti.put("jobs/10", "b");
+    nesti.put("jobs/1/tmp", "c");
+    nesti.pop("jobs/1");
+
+    assert_tree(
+        &nesti,
+        "
+        jobs
+        `- 10                       b
+        ",
+    );
+}
+
+#[test]
+fn unicode_glyphs_by_default() {
+    let nesti = Nesti::new();
+    nesti.put("a/b", "1");
+    nesti.put("a/c", "2");
+
+    let rendered = nesti.render_to_string(RenderOptions::new().strip_ansi());
+
+    assert!(rendered.contains("├─ b"));
+    assert!(rendered.contains("╰─ c"));
+}
+
+#[test]
+fn strip_ansi_removes_escape_codes() {
+    let nesti = Nesti::new();
+    nesti.put("a/b", true);
+
+    let colored = nesti.render_to_string(RenderOptions::new());
+    let plain = nesti.render_to_string(RenderOptions::new().strip_ansi());
+
+    assert!(colored.contains('\x1b'));
+    assert!(!plain.contains('\x1b'));
+}
+
+#[test]
+fn narrow_width_truncates_labels_and_values() {
+    let nesti = Nesti::new();
+    nesti.put("a_really_long_segment_name/leaf", "a value that is long");
+
+    let rendered = nesti.render_to_string(RenderOptions::plain().width(40));
+
+    for line in rendered.lines() {
+        assert!(line.chars().count() <= 40, "{line:?} is wider than 40");
+    }
+    assert!(rendered.contains("..."));
+}
+
+#[test]
+fn wide_chars_keep_columns_aligned() {
+    let nesti = Nesti::new();
+    nesti.put("dc/東京", "稼働中");
+    nesti.put("dc/berlin", "up");
+    nesti.put("dc/launch🚀", "ok");
+
+    assert_tree(
+        &nesti,
+        "
+        dc
+        |- 東京                     稼働中
+        |- berlin                       up
+        `- launch🚀                     ok
+        ",
+    );
+
+    for width in [20, 24, 30] {
+        let rendered = nesti.render_to_string(RenderOptions::plain().width(width));
+        for line in rendered.lines() {
+            assert!(line.width() <= width, "{line:?} is wider than {width}");
+        }
+    }
+}
+
+#[test]
+fn short_height_adds_more_rows_marker() {
+    let nesti = Nesti::new();
+    for i in 0..10 {
+        nesti.put(format!("workers/{i}"), Integer(i));
+    }
+
+    let rendered = nesti.render_to_string(RenderOptions::plain().height(8));
+
+    assert!(rendered.contains("... 7 more rows"));
+    assert!(rendered.contains("workers"));
+    assert!(!rendered.contains("|- 9"));
+}
+
+#[test]
+fn focus_scrolls_row_into_view() {
+    let nesti = Nesti::new();
+    for i in 0..10 {
+        nesti.put(format!("workers/{i}"), Integer(i));
+    }
+    nesti.set_focus("workers/9");
+
+    let rendered = nesti.render_to_string(RenderOptions::plain().height(8));
+
+    assert!(rendered.contains("`- 9"));
+    assert!(rendered.contains("more rows"));
+}
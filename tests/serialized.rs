@@ -0,0 +1,160 @@
+#![cfg(feature = "serde")]
+
+use std::sync::{
+    atomic::{AtomicUsize, Ordering},
+    Arc, Mutex,
+};
+
+use nesti::{
+    testing::{assert_tree, render},
+    Children, Element, Nesti, Serialized,
+};
+use serde::Serialize;
+
+#[derive(Serialize)]
+struct Config {
+    name: &'static str,
+    workers: u32,
+    ratio: f64,
+    verbose: bool,
+    regions: Vec<&'static str>,
+    limits: Limits,
+}
+
+#[derive(Serialize)]
+struct Limits {
+    memory: u64,
+    offset: i32,
+}
+
+fn config() -> Config {
+    Config {
+        name: "ingest",
+        workers: 4,
+        ratio: 0.5,
+        verbose: true,
+        regions: vec!["eu-west", "us-east"],
+        limits: Limits {
+            memory: 2048,
+            offset: -3,
+        },
+    }
+}
+
+#[test]
+fn struct_becomes_subtree_in_field_order() {
+    let nesti = Nesti::new();
+    nesti.put("config", Serialized(config()));
+
+    assert_tree(
+        &nesti,
+        "
+        config
+        |- name                      ingest
+        |- workers                        4
+        |- ratio                       0.50
+        |- verbose                   TRUE ●
+        |- regions
+        |  |- 0                     eu-west
+        |  `- 1                     us-east
+        `- limits
+           |- memory                  2,048
+           `- offset                     -3
+        ",
+    );
+}
+
+#[test]
+fn removed_members_are_despawned() {
+    let nesti = Nesti::new();
+    nesti.put("config", Serialized(config()));
+
+    let mut smaller = config();
+    smaller.regions.pop();
+    nesti.put("config", Serialized(smaller));
+
+    assert!(nesti.paths().all(|path| path != "config/regions/1"));
+    assert!(nesti.paths().any(|path| path == "config/regions/0"));
+}
+
+#[test]
+fn serialization_error_is_shown() {
+    let nesti = Nesti::new();
+    let mut map = std::collections::BTreeMap::new();
+    map.insert(vec![1u8], "a");
+    nesti.put("broken", Serialized(map));
+
+    assert_tree(
+        &nesti,
+        "
+        broken                      key must be a string
+        ",
+    );
+}
+
+#[test]
+fn keys_with_slashes_or_empty_stay_one_row() {
+    let nesti = Nesti::new();
+    let mut map = std::collections::BTreeMap::new();
+    map.insert("", 1);
+    map.insert("a/b", 2);
+    map.insert("50%", 3);
+    nesti.put("keys", Serialized(map));
+
+    assert_eq!(
+        nesti.paths().collect::<Vec<_>>(),
+        ["keys", "keys/\"\"", "keys/50%", "keys/a\u{2215}b"]
+    );
+}
+
+/// Counts how often it is serialized.
+struct Counted(&'static AtomicUsize);
+
+impl Serialize for Counted {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.fetch_add(1, Ordering::Relaxed);
+        vec![1, 2].serialize(serializer)
+    }
+}
+
+#[test]
+fn serializes_once_per_put_and_flush() {
+    static COUNT: AtomicUsize = AtomicUsize::new(0);
+    let nesti = Nesti::new();
+    nesti.put("counted", Serialized(Counted(&COUNT)));
+    assert_eq!(COUNT.load(Ordering::Relaxed), 1);
+
+    for _ in 0..3 {
+        render(&nesti);
+    }
+    assert_eq!(COUNT.load(Ordering::Relaxed), 4);
+    assert_eq!(
+        nesti.get("counted/1").unwrap().content.as_deref(),
+        Some("2")
+    );
+}
+
+/// Puts its shared items as a `Serialized` built anew on every call.
+struct Queue(Arc<Mutex<Vec<u32>>>);
+
+impl Element for Queue {
+    fn children(&self, children: &mut Children) {
+        children.put("items", &Serialized(self.0.lock().unwrap().clone()));
+    }
+}
+
+#[test]
+fn serialized_built_in_children_is_not_served_stale() {
+    let items = Arc::new(Mutex::new(vec![1, 2, 3]));
+    let nesti = Nesti::new();
+    nesti.put("queue", Queue(items.clone()));
+    render(&nesti);
+
+    items.lock().unwrap().pop();
+    render(&nesti);
+
+    assert_eq!(
+        nesti.children("queue/items").collect::<Vec<_>>(),
+        ["queue/items/0", "queue/items/1"]
+    );
+}
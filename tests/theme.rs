@@ -0,0 +1,67 @@
+use nesti::{
+    style::{Palette16, Styles, TextFg},
+    Background, Color, ColorSupport, Nesti, Red, RenderOptions, Role, Text, Theme, Themed,
+};
+
+fn colored(nesti: &Nesti) -> String {
+    nesti.render_to_string(RenderOptions::new())
+}
+
+#[test]
+fn bool_takes_its_colour_from_the_theme() {
+    let nesti = Nesti::new();
+    nesti.put("healthy", true);
+
+    let snapshot = nesti.get("healthy").unwrap();
+    assert_eq!(snapshot.role, Some(Role::Ok));
+    assert!(snapshot.style.is_none());
+
+    nesti.set_theme(Theme::dark().with(Role::Ok, Styles::new().with(TextFg(Palette16::Blue))));
+    assert!(colored(&nesti).contains(";34m TRUE"));
+}
+
+#[test]
+fn swapping_theme_restyles_existing_rows() {
+    let nesti = Nesti::new();
+    nesti.put("db/status", Themed(Role::Warn, Text("degraded")));
+
+    let dark = colored(&nesti);
+    assert!(dark.contains("\x1b[90m"));
+    assert!(dark.contains(";33mdegraded"));
+
+    nesti.set_theme(Theme::light());
+    assert!(colored(&nesti).contains(";35mdegraded"));
+
+    nesti.set_theme(Theme::high_contrast());
+    let high = colored(&nesti);
+    assert!(high.contains("\x1b[97m"));
+    assert!(high.contains("\x1b[1mdb"));
+}
+
+#[test]
+fn fixed_style_wins_over_role() {
+    let nesti = Nesti::new();
+    nesti.put("healthy", nesti::Cyan(true));
+
+    let snapshot = nesti.get("healthy").unwrap();
+    assert!(snapshot.style.is_some());
+    assert!(colored(&nesti).contains(";36m TRUE"));
+}
+
+#[test]
+fn themed_keeps_outer_background() {
+    let nesti = Nesti::new();
+    nesti.put(
+        "db/status",
+        Background(Color::Rgb(1, 2, 3), Themed(Role::Warn, Text("degraded"))),
+    );
+    nesti.put("db/load", Themed(Role::Accent, Red(Text("high"))));
+
+    let out = nesti.render_to_string(RenderOptions::new().color_support(ColorSupport::TrueColor));
+    assert!(out.contains(";33;48;2;1;2;3mdegraded"));
+    assert!(out.contains(";36mhigh"));
+
+    nesti.set_theme(Theme::light());
+    let out = nesti.render_to_string(RenderOptions::new().color_support(ColorSupport::TrueColor));
+    assert!(out.contains(";35;48;2;1;2;3mdegraded"));
+}
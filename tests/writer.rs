@@ -0,0 +1,44 @@
+use nesti::{testing::SharedBuffer, Integer, Nesti, RenderMode};
+
+#[test]
+fn renders_into_a_buffer() {
+    let buffer = SharedBuffer::new();
+    let nesti = Nesti::with_writer(buffer.clone());
+    nesti.put("jobs/queued", Integer(3));
+    nesti.flush().unwrap();
+
+    let out = buffer.contents();
+    assert!(out.contains("queued"));
+    assert!(out.contains('3'));
+    assert!(!out.contains('\x1b'));
+}
+
+#[test]
+fn set_writer_redirects_output() {
+    let first = SharedBuffer::new();
+    let second = SharedBuffer::new();
+    let nesti = Nesti::with_writer(first.clone());
+    nesti.put("jobs", Integer(1));
+    nesti.flush().unwrap();
+
+    nesti.set_writer(second.clone());
+    nesti.put("jobs", Integer(2));
+    nesti.flush().unwrap();
+
+    assert!(first.contents().contains('1'));
+    assert!(!first.contents().contains('2'));
+    assert!(second.contents().contains('2'));
+}
+
+#[test]
+fn interactive_mode_draws_with_escapes() {
+    let buffer = SharedBuffer::new();
+    let nesti = Nesti::with_writer(buffer.clone());
+    nesti.set_mode(RenderMode::Interactive);
+    nesti.put("jobs", Integer(1));
+    nesti.flush().unwrap();
+
+    let out = buffer.contents();
+    assert!(out.contains("jobs"));
+    assert!(out.contains('\x1b'));
+}